@@ -11,7 +11,18 @@ mod uart;
 
 use crate::uart::Uart;
 use avr_device::atmega328p as pac;
-use avr_q::unit_tests::{TestOps, run_tests};
+use avr_q::unit_tests::{TestOps, run_test_group};
+
+/// The test group to build and run.
+///
+/// Select the group with the `AVR_Q_TEST_GROUP` environment variable at build time.
+const TEST_GROUP: u8 = match option_env!("AVR_Q_TEST_GROUP") {
+    Some(group) => match u8::from_str_radix(group, 10) {
+        Ok(group) => group,
+        Err(_) => panic!("AVR_Q_TEST_GROUP is not a valid test group number"),
+    },
+    None => 0,
+};
 
 struct TestRunner<'a> {
     uart: &'a Uart,
@@ -56,7 +67,7 @@ fn main() -> ! {
     let uart = Uart::new(dp.USART0);
     let test = TestRunner { uart: &uart };
 
-    run_tests(&test);
+    run_test_group::<TEST_GROUP>(&test);
 
     loop {
         avr_device::interrupt::disable();
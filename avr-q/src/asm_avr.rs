@@ -0,0 +1,153 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use core::arch::asm;
+
+/// Signed fractional 16 x 16 bit multiplication.
+///
+/// Returns the 32 bit product shifted left by one.
+/// The product of `i16::MIN * i16::MIN` wraps around to `i32::MIN`.
+#[inline(always)]
+pub fn asm_fmuls16(a: i16, b: i16) -> i32 {
+    let [al, ah] = a.to_le_bytes();
+    let [bl, bh] = b.to_le_bytes();
+    let c0: u8;
+    let c1: u8;
+    let c2: u8;
+    let c3: u8;
+    // SAFETY: The assembly code is manually checked.
+    //         r0 is the temporary register and can be clobbered.
+    //         r1 is the zero register and it is cleared at the end.
+    unsafe {
+        asm!(
+            "   clr {z}",
+
+            "   fmuls r17, r19",        // (signed)ah * (signed)bh << 1
+            "   mov {c2}, r0",
+            "   mov {c3}, r1",
+
+            "   fmul r16, r18",         // al * bl << 1
+            "   adc {c2}, {z}",
+            "   mov {c0}, r0",
+            "   mov {c1}, r1",
+
+            "   fmulsu r17, r18",       // (signed)ah * bl << 1
+            "   sbc {c3}, {z}",
+            "   add {c1}, r0",
+            "   adc {c2}, r1",
+            "   adc {c3}, {z}",
+
+            "   fmulsu r19, r16",       // (signed)bh * al << 1
+            "   sbc {c3}, {z}",
+            "   add {c1}, r0",
+            "   adc {c2}, r1",
+            "   adc {c3}, {z}",
+
+            "   clr __zero_reg__",
+
+            in("r16") al,               // fmul operands must be in r16..r23
+            in("r17") ah,
+            in("r18") bl,
+            in("r19") bh,
+
+            c0 = out(reg) c0,           // product
+            c1 = out(reg) c1,
+            c2 = out(reg) c2,
+            c3 = out(reg) c3,
+
+            z = out(reg) _,             // zero
+
+            options(pure, nomem, nostack),
+        );
+    }
+    i32::from_le_bytes([c0, c1, c2, c3])
+}
+
+/// Unsigned 8 x 8 bit multiplication.
+///
+/// Returns the 16 bit product.
+#[inline(always)]
+pub fn asm_mulu8(a: u8, b: u8) -> u16 {
+    let c0: u8;
+    let c1: u8;
+    // SAFETY: The assembly code is manually checked.
+    //         r0 is the temporary register and can be clobbered.
+    //         r1 is the zero register and it is cleared at the end.
+    unsafe {
+        asm!(
+            "   mul {a}, {b}",          // a * b
+            "   mov {c0}, r0",
+            "   mov {c1}, r1",
+
+            "   clr __zero_reg__",
+
+            a = in(reg) a,              // factors
+            b = in(reg) b,
+
+            c0 = out(reg) c0,           // product
+            c1 = out(reg) c1,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    u16::from_le_bytes([c0, c1])
+}
+
+/// Unsigned 16 x 16 bit multiplication.
+///
+/// Returns the 32 bit product.
+#[inline(always)]
+pub fn asm_mulu16(a: u16, b: u16) -> u32 {
+    let [al, ah] = a.to_le_bytes();
+    let [bl, bh] = b.to_le_bytes();
+    let c0: u8;
+    let c1: u8;
+    let c2: u8;
+    let c3: u8;
+    // SAFETY: The assembly code is manually checked.
+    //         r0 is the temporary register and can be clobbered.
+    //         r1 is the zero register and it is cleared at the end.
+    unsafe {
+        asm!(
+            "   clr {z}",
+
+            "   mul {ah}, {bh}",        // ah * bh
+            "   mov {c2}, r0",
+            "   mov {c3}, r1",
+
+            "   mul {al}, {bl}",        // al * bl
+            "   mov {c0}, r0",
+            "   mov {c1}, r1",
+
+            "   mul {ah}, {bl}",        // ah * bl
+            "   add {c1}, r0",
+            "   adc {c2}, r1",
+            "   adc {c3}, {z}",
+
+            "   mul {bh}, {al}",        // bh * al
+            "   add {c1}, r0",
+            "   adc {c2}, r1",
+            "   adc {c3}, {z}",
+
+            "   clr __zero_reg__",
+
+            al = in(reg) al,            // factors
+            ah = in(reg) ah,
+            bl = in(reg) bl,
+            bh = in(reg) bh,
+
+            c0 = out(reg) c0,           // product
+            c1 = out(reg) c1,
+            c2 = out(reg) c2,
+            c3 = out(reg) c3,
+
+            z = out(reg) _,             // zero
+
+            options(pure, nomem, nostack),
+        );
+    }
+    u32::from_le_bytes([c0, c1, c2, c3])
+}
+
+// vim: ts=4 sw=4 expandtab
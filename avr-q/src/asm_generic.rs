@@ -0,0 +1,30 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+/// Signed fractional 16 x 16 bit multiplication.
+///
+/// Returns the 32 bit product shifted left by one.
+/// The product of `i16::MIN * i16::MIN` wraps around to `i32::MIN`.
+#[inline(always)]
+pub fn asm_fmuls16(a: i16, b: i16) -> i32 {
+    (a as i32 * b as i32) << 1
+}
+
+/// Unsigned 8 x 8 bit multiplication.
+///
+/// Returns the 16 bit product.
+#[inline(always)]
+pub fn asm_mulu8(a: u8, b: u8) -> u16 {
+    a as u16 * b as u16
+}
+
+/// Unsigned 16 x 16 bit multiplication.
+///
+/// Returns the 32 bit product.
+#[inline(always)]
+pub fn asm_mulu16(a: u16, b: u16) -> u32 {
+    a as u32 * b as u32
+}
+
+// vim: ts=4 sw=4 expandtab
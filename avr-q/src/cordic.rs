@@ -0,0 +1,261 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use avr_int24::I24;
+
+/// Number of CORDIC iterations.
+const ITERATIONS: usize = 16;
+
+/// `atan(2^-i)` in radians with 20 fractional bits.
+const ATAN: [i32; ITERATIONS] = [
+    823550, 486170, 256879, 130396, 65451, 32757, 16383, 8192, //
+    4096, 2048, 1024, 512, 256, 128, 64, 32,
+];
+
+/// `pi / 2` in radians with 20 fractional bits.
+const HALF_PI: i32 = 1647099;
+
+/// The vector is normalized, so that its larger component
+/// is in the range `[NORM_MIN, NORM_MAX)`.
+/// The CORDIC gain of about 1.65 then can't overflow 24 bits.
+const NORM_MIN: i32 = 1 << 20;
+const NORM_MAX: i32 = 1 << 21;
+
+/// Calculate the angle of the vector `(x, y)` in radians.
+///
+/// This is a CORDIC in vectoring mode.
+/// It only uses 24 bit additions, subtractions and shifts.
+///
+/// Returns the raw Q.8 angle in the range `[-pi, pi]`, rounded to nearest.
+/// The angle of the zero vector is zero.
+#[inline(never)]
+pub(crate) fn atan2_i24(y: I24, x: I24) -> I24 {
+    let zero = I24::from_i32(0);
+    if x == zero && y == zero {
+        return zero;
+    }
+    // Rotate the vector by +-pi/2 into the right half plane.
+    let (mut x, mut y, mut z) = if x >= zero {
+        (x, y, zero)
+    } else if y >= zero {
+        (y, -x, I24::from_i32(HALF_PI))
+    } else {
+        (-y, x, I24::from_i32(-HALF_PI))
+    };
+    // Normalize the vector. This doesn't change its angle.
+    let norm_min = I24::from_i32(NORM_MIN);
+    let norm_max = I24::from_i32(NORM_MAX);
+    let mut m = if x >= y.abs() { x } else { y.abs() };
+    if m >= norm_max {
+        x >>= 2;
+        y >>= 2;
+    } else {
+        while m < norm_min {
+            x <<= 1;
+            y <<= 1;
+            m <<= 1;
+        }
+    }
+    // Rotate the vector onto the x axis and accumulate the rotation angles.
+    let mut i = 0;
+    while i < ITERATIONS {
+        let dx = x >> i as u8;
+        let dy = y >> i as u8;
+        let a = I24::from_i32(ATAN[i]);
+        if y < zero {
+            x -= dy;
+            y += dx;
+            z -= a;
+        } else {
+            x += dy;
+            y -= dx;
+            z += a;
+        }
+        i += 1;
+    }
+    (z + I24::from_i32(1 << 11)) >> 12
+}
+
+/// Calculate the angle of the vector `(x, y)` in radians.
+/// Const variant of [atan2_i24].
+///
+/// Returns the raw Q.8 angle in the range `[-pi, pi]`, rounded to nearest.
+/// The angle of the zero vector is zero.
+pub(crate) const fn const_atan2_i24(y: I24, x: I24) -> I24 {
+    let (x, y) = (x.to_i32(), y.to_i32());
+    if x == 0 && y == 0 {
+        return I24::from_i32(0);
+    }
+    // The negation saturates like the 24 bit negation.
+    let (mut x, mut y, mut z) = if x >= 0 {
+        (x, y, 0)
+    } else if y >= 0 {
+        (y, sat_neg(x), HALF_PI)
+    } else {
+        (sat_neg(y), x, -HALF_PI)
+    };
+    let mut m = if x >= y.abs() { x } else { y.abs() };
+    if m >= NORM_MAX {
+        x >>= 2;
+        y >>= 2;
+    } else {
+        while m < NORM_MIN {
+            x <<= 1;
+            y <<= 1;
+            m <<= 1;
+        }
+    }
+    let mut i = 0;
+    while i < ITERATIONS {
+        let dx = x >> i;
+        let dy = y >> i;
+        if y < 0 {
+            x -= dy;
+            y += dx;
+            z -= ATAN[i];
+        } else {
+            x += dy;
+            y -= dx;
+            z += ATAN[i];
+        }
+        i += 1;
+    }
+    I24::from_i32((z + (1 << 11)) >> 12)
+}
+
+/// Negate a 24 bit value in 32 bit and saturate it to 24 bit.
+#[inline(always)]
+const fn sat_neg(v: i32) -> i32 {
+    if v == -0x80_0000 { 0x7F_FFFF } else { -v }
+}
+
+/// Calculate the arcsine of the raw Q.8 value `q` in radians.
+/// `q` is clamped to the range `[-1, 1]`.
+///
+/// This is a double iteration CORDIC. Every rotation is applied twice,
+/// so that the CORDIC gain of the rotated vector is `1 + 2^(-2i)` per iteration.
+/// The target `t` is scaled by the same gain, so no multiplication is needed.
+/// It only uses 24 bit additions, subtractions and shifts.
+///
+/// Returns the angle in the range `[-pi/2, pi/2]` with 20 fractional bits
+/// and `true`, if `q` was clamped.
+#[inline(always)]
+fn asin_z(q: I24) -> (I24, bool) {
+    let zero = I24::from_i32(0);
+    let one = I24::from_i32(1 << 8);
+    let (a, clamped) = if q > one || q < -one {
+        (one, true)
+    } else {
+        (q.abs(), false)
+    };
+    let mut x = I24::from_i32(1 << 20);
+    let mut y = zero;
+    let mut z = zero;
+    let mut t = a << 12;
+    let mut i = 0;
+    while i < ITERATIONS {
+        let a = I24::from_i32(ATAN[i] << 1);
+        let up = (y <= t) == (x >= zero);
+        let mut j = 0;
+        while j < 2 {
+            let dx = x >> i as u8;
+            let dy = y >> i as u8;
+            if up {
+                x -= dy;
+                y += dx;
+            } else {
+                x += dy;
+                y -= dx;
+            }
+            j += 1;
+        }
+        if up {
+            z += a;
+        } else {
+            z -= a;
+        }
+        t += t >> (i << 1) as u8;
+        i += 1;
+    }
+    (if q < zero { -z } else { z }, clamped)
+}
+
+/// Calculate the arcsine of the raw Q.8 value `q` in radians.
+/// Const variant of [asin_z].
+const fn const_asin_z(q: i32) -> i32 {
+    let a = if q > 1 << 8 || q < -(1 << 8) {
+        1 << 8
+    } else {
+        q.abs()
+    };
+    let mut x: i32 = 1 << 20;
+    let mut y: i32 = 0;
+    let mut z: i32 = 0;
+    let mut t = a << 12;
+    let mut i = 0;
+    while i < ITERATIONS {
+        let up = (y <= t) == (x >= 0);
+        let mut j = 0;
+        while j < 2 {
+            let dx = x >> i;
+            let dy = y >> i;
+            if up {
+                x -= dy;
+                y += dx;
+            } else {
+                x += dy;
+                y -= dx;
+            }
+            j += 1;
+        }
+        if up {
+            z += ATAN[i] << 1;
+        } else {
+            z -= ATAN[i] << 1;
+        }
+        t += t >> (i << 1);
+        i += 1;
+    }
+    if q < 0 { -z } else { z }
+}
+
+/// Calculate the arcsine of the raw Q.8 value `q` in radians.
+/// `q` is clamped to the range `[-1, 1]`.
+///
+/// Returns the raw Q.8 angle in the range `[-pi/2, pi/2]`, rounded to nearest,
+/// and `true`, if `q` was clamped.
+#[inline(never)]
+pub(crate) fn asin_i24(q: I24) -> (I24, bool) {
+    let (z, clamped) = asin_z(q);
+    ((z + I24::from_i32(1 << 11)) >> 12, clamped)
+}
+
+/// Calculate the arcsine of the raw Q.8 value `q` in radians.
+/// Const variant of [asin_i24].
+///
+/// Returns the raw Q.8 angle in the range `[-pi/2, pi/2]`, rounded to nearest.
+pub(crate) const fn const_asin_i24(q: I24) -> I24 {
+    I24::from_i32((const_asin_z(q.to_i32()) + (1 << 11)) >> 12)
+}
+
+/// Calculate the arccosine of the raw Q.8 value `q` in radians.
+/// `q` is clamped to the range `[-1, 1]`.
+///
+/// Returns the raw Q.8 angle in the range `[0, pi]`, rounded to nearest,
+/// and `true`, if `q` was clamped.
+#[inline(never)]
+pub(crate) fn acos_i24(q: I24) -> (I24, bool) {
+    let (z, clamped) = asin_z(q);
+    ((I24::from_i32(HALF_PI + (1 << 11)) - z) >> 12, clamped)
+}
+
+/// Calculate the arccosine of the raw Q.8 value `q` in radians.
+/// Const variant of [acos_i24].
+///
+/// Returns the raw Q.8 angle in the range `[0, pi]`, rounded to nearest.
+pub(crate) const fn const_acos_i24(q: I24) -> I24 {
+    I24::from_i32((HALF_PI + (1 << 11) - const_asin_z(q.to_i32())) >> 12)
+}
+
+// vim: ts=4 sw=4 expandtab
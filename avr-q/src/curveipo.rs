@@ -3,8 +3,8 @@
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
 macro_rules! impl_curveipo {
-    ($type:ty, $zero:expr) => {
-        impl curveipo::CurvePoint<$type> for ($type, $type) {
+    ([$($generics:tt)*] $type:ty, $zero:expr) => {
+        impl<$($generics)*> curveipo::CurvePoint<$type> for ($type, $type) {
             #[inline(always)]
             fn x(&self) -> $type {
                 self.0
@@ -16,7 +16,7 @@ macro_rules! impl_curveipo {
             }
         }
 
-        impl curveipo::CurveIpo for $type {
+        impl<$($generics)*> curveipo::CurveIpo for $type {
             #[inline(never)]
             fn lin_inter(
                 &self,
@@ -33,9 +33,55 @@ macro_rules! impl_curveipo {
             }
         }
     };
+    ($type:ty, $zero:expr) => {
+        impl_curveipo!([] $type, $zero);
+    };
+    (unsigned $type:ty) => {
+        impl curveipo::CurvePoint<$type> for ($type, $type) {
+            #[inline(always)]
+            fn x(&self) -> $type {
+                self.0
+            }
+
+            #[inline(always)]
+            fn y(&self) -> $type {
+                self.1
+            }
+        }
+
+        impl curveipo::CurveIpo for $type {
+            #[inline(never)]
+            fn lin_inter(
+                &self,
+                left: &impl curveipo::CurvePoint<Self>,
+                right: &impl curveipo::CurvePoint<Self>,
+            ) -> Self {
+                // The slope can't be negative in an unsigned type.
+                // Therefore, interpolate falling segments downwards from the left point.
+                let dx = right.x() - left.x();
+                let ix = *self - left.x();
+                if dx == <$type>::from_int(0) {
+                    left.y()
+                } else if right.y() >= left.y() {
+                    left.y() + (ix * ((right.y() - left.y()) / dx))
+                } else {
+                    left.y() - (ix * ((left.y() - right.y()) / dx))
+                }
+            }
+        }
+    };
 }
 
 impl_curveipo!(crate::Q7p8, crate::q7p8!(const 0));
-//TODO impl_curveipo!(crate::Q15p8, crate::q15p8!(const 0));
+impl_curveipo!(crate::Q15p8, crate::q15p8!(const 0));
+impl_curveipo!([const FRAC: usize] crate::Q<i8, FRAC>, crate::Q::<i8, FRAC>::from_q(0));
+impl_curveipo!([const FRAC: usize] crate::Q<i16, FRAC>, crate::Q::<i16, FRAC>::from_q(0));
+impl_curveipo!(
+    [const FRAC: usize] crate::Q<avr_int24::I24, FRAC>,
+    crate::Q::<avr_int24::I24, FRAC>::from_q(avr_int24::I24::from_i32(0))
+);
+impl_curveipo!([const FRAC: usize] crate::Q<i32, FRAC>, crate::Q::<i32, FRAC>::from_q(0));
+impl_curveipo!(unsigned crate::UQ8p8);
+impl_curveipo!(unsigned crate::UQ16p8);
 
 // vim: ts=4 sw=4 expandtab
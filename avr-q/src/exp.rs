@@ -0,0 +1,422 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    q::{mul_shr32, umul32},
+    round::Round,
+};
+
+/// `2^(2^-(i + 1))` with 31 fractional bits.
+const EXP2_BITS: [u32; 8] = [
+    3037000500, 2553802834, 2341847524, 2242560872, //
+    2194507417, 2170868212, 2159144272, 2153306067,
+];
+
+/// `ln(2)` with 32 fractional bits.
+const LN2: u32 = 2977044472;
+
+/// `log2(e)` with 30 fractional bits.
+const LOG2_E: u32 = 1549082005;
+
+/// Raw Q.8 results are clamped to this magnitude.
+/// The caller has to saturate the result to its format.
+const CLAMP: i32 = 1 << 24;
+
+/// Multiply two 32 bit values and return the upper 32 bits of the product.
+///
+/// The factors are split into 16 bit limbs and the product of the lower limbs is dropped.
+/// The result is at most 3 smaller than the exact upper 32 bits.
+#[inline(always)]
+const fn mul_hi32(a: u32, b: u32) -> u32 {
+    let (ah, al) = (a >> 16, a & 0xFFFF);
+    let (bh, bl) = (b >> 16, b & 0xFFFF);
+    ah * bh + ((ah * bl) >> 16) + ((al * bh) >> 16)
+}
+
+/// Calculate `2^frac` for a fraction with 32 fractional bits.
+///
+/// Returns the power with 31 fractional bits in the range `[2^31, 2^32)`.
+///
+/// The upper 8 bits of the fraction are handled with a table of `2^(2^-i)`.
+/// The remaining fraction `r` is smaller than `2^-8`
+/// and `2^r` is approximated by `1 + r * ln(2) + (r * ln(2))^2 / 2`.
+#[inline(always)]
+const fn exp2_frac(frac: u32) -> u32 {
+    let mut y: u32 = 1 << 31;
+    let mut i = 0;
+    while i < 8 {
+        if frac & (1 << (31 - i)) != 0 {
+            y = mul_hi32(y, EXP2_BITS[i]) << 1;
+        }
+        i += 1;
+    }
+    let r = frac & 0x00FF_FFFF;
+    if r != 0 {
+        let u = mul_hi32(r, LN2);
+        let e = u + (mul_hi32(u, u) >> 1);
+        y += mul_hi32(y, e);
+    }
+    y
+}
+
+/// Calculate `2^(int + frac / 2^32)`.
+///
+/// Returns the raw Q.8 power, rounded to nearest.
+/// Powers with a raw magnitude of more than 2^24 are clamped to 2^24.
+#[inline(always)]
+const fn exp2_parts(int: i32, frac: u32) -> i32 {
+    // The raw Q.8 power is y * 2^(int + 8 - 31).
+    let shift = 23 - int;
+    if shift <= 0 {
+        CLAMP
+    } else if shift > 32 {
+        0
+    } else {
+        let h = exp2_frac(frac) >> (shift - 1);
+        let p = (h >> 1) + (h & 1);
+        if p > CLAMP as u32 { CLAMP } else { p as i32 }
+    }
+}
+
+/// Apply the sign to a magnitude with 32 fractional bits,
+/// that is split into the integer part `hi` and the fraction `lo`.
+/// Returns the signed integer part, rounded towards negative infinity,
+/// and the fraction.
+#[inline(always)]
+const fn split_signed(neg: bool, hi: i32, lo: u32) -> (i32, u32) {
+    if !neg {
+        (hi, lo)
+    } else if lo == 0 {
+        (-hi, 0)
+    } else {
+        (-hi - 1, lo.wrapping_neg())
+    }
+}
+
+/// Calculate `2^x` for the raw Q.8 value `x`.
+///
+/// Returns the raw Q.8 power, rounded to nearest.
+/// Powers with a raw magnitude of more than 2^24 are clamped to 2^24.
+#[inline(never)]
+pub(crate) const fn exp2_q8(x: i32) -> i32 {
+    exp2_parts(x >> 8, ((x & 0xFF) as u32) << 24)
+}
+
+/// Calculate `e^x` for the raw Q.8 value `x`.
+///
+/// Returns the raw Q.8 power, rounded to nearest.
+/// Powers with a raw magnitude of more than 2^24 are clamped to 2^24.
+#[inline(never)]
+pub(crate) const fn exp_q8(x: i32) -> i32 {
+    // e^16 is out of range and e^-16 rounds to zero.
+    if x >= 16 << 8 {
+        return CLAMP;
+    }
+    if x <= -(16 << 8) {
+        return 0;
+    }
+    // x * log2(e) with 38 fractional bits.
+    // The magnitude of x is at most 2^12, so that the limb products fit into 32 bits.
+    let a = x.unsigned_abs();
+    let hi = a * (LOG2_E >> 16);
+    let lo = a * (LOG2_E & 0xFFFF);
+    let s = hi + (lo >> 16);
+    let int = (s >> 22) as i32;
+    let frac = (s << 10) | ((lo & 0xFFFF) >> 6);
+    let (int, frac) = split_signed(x < 0, int, frac);
+    exp2_parts(int, frac)
+}
+
+/// Calculate `log2(q / 2^8)` for the positive raw Q.8 value `q`.
+///
+/// Returns the logarithm with 24 fractional bits, rounded towards negative infinity.
+/// The logarithm is less than `2^-23` too small.
+///
+/// The fraction of the logarithm is calculated bit by bit
+/// by repeatedly squaring the normalized mantissa.
+#[inline(always)]
+const fn log2_q24(q: u32) -> i32 {
+    let e = 31 - q.leading_zeros();
+    // Normalize the mantissa to [1, 2) with 31 fractional bits.
+    let mut m = q << (31 - e);
+    let mut frac: u32 = 0;
+    let mut i = 0;
+    while i < 24 {
+        // m^2 with 30 fractional bits.
+        let m2 = mul_hi32(m, m);
+        frac <<= 1;
+        if m2 >= 1 << 31 {
+            // m^2 >= 2
+            frac |= 1;
+            m = m2;
+        } else {
+            m = m2 << 1;
+        }
+        i += 1;
+    }
+    ((e as i32 - 8) << 24) + frac as i32
+}
+
+/// Round a value with 24 fractional bits to a raw Q.8 value.
+#[inline(always)]
+const fn round_q24(v: i32) -> i32 {
+    (v + (1 << 15)) >> 16
+}
+
+/// Calculate `log2(q / 2^8)` for the raw Q.8 value `q`.
+///
+/// Returns the raw Q.8 logarithm, rounded to nearest.
+/// Returns `-2^24`, if `q` is not positive.
+#[inline(never)]
+pub(crate) const fn log2_q8(q: i32) -> i32 {
+    if q <= 0 {
+        -CLAMP
+    } else {
+        round_q24(log2_q24(q as u32))
+    }
+}
+
+/// Calculate `ln(q / 2^8)` for the raw Q.8 value `q`.
+///
+/// Returns the raw Q.8 logarithm, rounded to nearest.
+/// Returns `-2^24`, if `q` is not positive.
+#[inline(never)]
+pub(crate) const fn ln_q8(q: i32) -> i32 {
+    if q <= 0 {
+        return -CLAMP;
+    }
+    // ln(x) = log2(x) * ln(2)
+    let l = log2_q24(q as u32);
+    let m = mul_hi32(l.unsigned_abs(), LN2) as i32;
+    round_q24(if l < 0 { -m } else { m })
+}
+
+/// Calculate `(b / 2^8)^(y / 2^8)` for the raw Q.8 values `b` and `y`.
+///
+/// Returns the raw Q.8 power, rounded to nearest, and `false`.
+/// Powers with a raw magnitude of more than 2^24 are clamped to 2^24.
+///
+/// A zero base results in zero for positive exponents, in one for a zero exponent
+/// and in `2^24` for negative exponents.
+/// A negative base results in zero and `true`.
+#[inline(never)]
+pub(crate) const fn pow_q8(b: i32, y: i32) -> (i32, bool) {
+    if b < 0 {
+        return (0, true);
+    }
+    if b == 0 {
+        return if y > 0 {
+            (0, false)
+        } else if y == 0 {
+            (1 << 8, false)
+        } else {
+            (CLAMP, false)
+        };
+    }
+    // b^y = 2^(y * log2(b))
+    // The product has 32 fractional bits.
+    let l = log2_q24(b as u32);
+    let neg = (l < 0) != (y < 0);
+    let (a, c) = (y.unsigned_abs(), l.unsigned_abs());
+    let (ah, al) = (a >> 16, a & 0xFFFF);
+    let (ch, cl) = (c >> 16, c & 0xFFFF);
+    // The magnitude of y is less than 2^24 and the magnitude of l is less than 2^28.
+    // Therefore, the limb products and their sums fit into 32 bits.
+    let mid = ah * cl + al * ch;
+    let lo = al * cl;
+    let s = mid + (lo >> 16);
+    let int = ah * ch + (s >> 16);
+    if int >= 32 {
+        // The power is out of range or rounds to zero.
+        return (if neg { 0 } else { CLAMP }, false);
+    }
+    let frac = (s << 16) | (lo & 0xFFFF);
+    let (int, frac) = split_signed(neg, int as i32, frac);
+    (exp2_parts(int, frac), false)
+}
+
+/// Multiply two normalized mantissas in the range `[2^63, 2^64)`.
+///
+/// Returns the upper 64 bits of the product, normalized to the range `[2^63, 2^64)`,
+/// and the exponent increment of the normalization.
+/// The product is rounded towards zero.
+/// The calculation is split into 32 bit limbs.
+#[inline(always)]
+const fn mul_mant(a: u64, b: u64) -> (u64, i32) {
+    let (ah, al) = (a >> 32, a & 0xFFFF_FFFF);
+    let (bh, bl) = (b >> 32, b & 0xFFFF_FFFF);
+    let mid1 = ah * bl;
+    let mid2 = al * bh;
+    // Bits 32 to 65 of the product.
+    let t = ((al * bl) >> 32) + (mid1 & 0xFFFF_FFFF) + (mid2 & 0xFFFF_FFFF);
+    let hi = ah * bh + (mid1 >> 32) + (mid2 >> 32) + (t >> 32);
+    if hi >> 63 != 0 {
+        (hi, 1)
+    } else {
+        ((hi << 1) | ((t >> 31) & 1), 0)
+    }
+}
+
+/// Calculate `(q / 2^8)^n` for the raw Q.8 value `q`.
+/// Const variant of [powi_q8].
+///
+/// Returns the raw Q.8 power, rounded to nearest with ties rounded away from zero.
+/// Powers with a raw magnitude of more than 2^24 are clamped to 2^24.
+///
+/// This is exponentiation by squaring on a normalized 64 bit mantissa,
+/// so that the power is only rounded and clamped once at the end.
+/// The result is exact, if all intermediate products fit into the mantissa.
+/// This is the case for raw magnitudes below 2^16 and `n <= 8`
+/// and for raw magnitudes below 2^24 and `n <= 4`.
+/// Otherwise, the relative error of the power before rounding is less than 2^-54.
+pub(crate) const fn const_powi_q8(q: i32, n: u8) -> i32 {
+    if n == 0 {
+        return 1 << 8;
+    }
+    if q == 0 {
+        return 0;
+    }
+    // The magnitude is m / 2^63 * 2^e.
+    let a = q.unsigned_abs();
+    let lz = a.leading_zeros();
+    let mut base = ((a << lz) as u64) << 32;
+    let mut base_e = 23 - lz as i32;
+    let mut m: u64 = 1 << 63;
+    let mut e: i32 = 0;
+    let mut n_rem = n;
+    loop {
+        if n_rem & 1 != 0 {
+            let (p, inc) = mul_mant(m, base);
+            m = p;
+            e += base_e + inc;
+        }
+        n_rem >>= 1;
+        if n_rem == 0 {
+            break;
+        }
+        let (p, inc) = mul_mant(base, base);
+        base = p;
+        base_e = 2 * base_e + inc;
+    }
+    // The raw Q.8 power is m * 2^(e + 8 - 63).
+    let p = if e >= 16 {
+        CLAMP
+    } else {
+        let shift = 55 - e;
+        if shift > 64 {
+            0
+        } else {
+            let h = (m >> (shift - 1)) as u32;
+            ((h >> 1) + (h & 1)) as i32
+        }
+    };
+    if q < 0 && n & 1 != 0 { -p } else { p }
+}
+
+/// A normalized 64 bit mantissa as its upper and its lower 32 bits.
+type Mant = (u32, u32);
+
+/// Multiply two normalized mantissas in the range `[2^63, 2^64)`.
+/// Runtime variant of [mul_mant].
+///
+/// The product is composed of the 32x32 bit products of [umul32],
+/// so that no 64 bit arithmetic is needed.
+#[inline(never)]
+fn mul_mant32((ah, al): Mant, (bh, bl): Mant) -> (Mant, i32) {
+    let (hh_lo, hh_hi) = umul32(ah, bh);
+    let (mid1_lo, mid1_hi) = umul32(ah, bl);
+    let (mid2_lo, mid2_hi) = umul32(al, bh);
+    let (_, ll_hi) = umul32(al, bl);
+    // Bits 32 to 63 of the product and the carry into bit 64.
+    let (t, c1) = ll_hi.overflowing_add(mid1_lo);
+    let (t, c2) = t.overflowing_add(mid2_lo);
+    // Bits 64 to 127 of the product.
+    let (lo, c3) = hh_lo.overflowing_add(mid1_hi);
+    let (lo, c4) = lo.overflowing_add(mid2_hi);
+    let (lo, c5) = lo.overflowing_add(c1 as u32 + c2 as u32);
+    let hi = hh_hi + c3 as u32 + c4 as u32 + c5 as u32;
+    if hi >> 31 != 0 {
+        ((hi, lo), 1)
+    } else {
+        (((hi << 1) | (lo >> 31), (lo << 1) | (t >> 31)), 0)
+    }
+}
+
+/// Calculate `(q / 2^8)^n` for the raw Q.8 value `q`.
+///
+/// Returns the raw Q.8 power, rounded to nearest with ties rounded away from zero.
+/// Powers with a raw magnitude of more than 2^24 are clamped to 2^24.
+///
+/// This is the same exponentiation by squaring as in [const_powi_q8]
+/// and the result is identical.
+/// The 64 bit mantissa is split into two 32 bit halves,
+/// so that no 64 bit arithmetic is needed.
+#[inline(never)]
+pub(crate) fn powi_q8(q: i32, n: u8) -> i32 {
+    if n == 0 {
+        return 1 << 8;
+    }
+    if q == 0 {
+        return 0;
+    }
+    // The magnitude is m / 2^63 * 2^e.
+    let a = q.unsigned_abs();
+    let lz = a.leading_zeros();
+    let mut base: Mant = (a << lz, 0);
+    let mut base_e = 23 - lz as i32;
+    let mut m: Mant = (1 << 31, 0);
+    let mut e: i32 = 0;
+    let mut n_rem = n;
+    loop {
+        if n_rem & 1 != 0 {
+            let (p, inc) = mul_mant32(m, base);
+            m = p;
+            e += base_e + inc;
+        }
+        n_rem >>= 1;
+        if n_rem == 0 {
+            break;
+        }
+        let (p, inc) = mul_mant32(base, base);
+        base = p;
+        base_e = 2 * base_e + inc;
+    }
+    // The raw Q.8 power is m * 2^(e + 8 - 63).
+    // The shift is at least 40, so only the upper half of the mantissa is needed.
+    let p = if e >= 16 {
+        CLAMP
+    } else {
+        let shift = 55 - e;
+        if shift > 64 {
+            0
+        } else {
+            let h = m.0 >> (shift - 33);
+            ((h >> 1) + (h & 1)) as i32
+        }
+    };
+    if q < 0 && n & 1 != 0 { -p } else { p }
+}
+
+/// Calculate `(q / 2^8)^3` for the raw Q.8 value `q`.
+///
+/// Returns the raw Q.8 cube, rounded to nearest with ties rounded away from zero.
+/// Cubes with a raw magnitude of more than 2^24 are clamped to 2^24.
+/// The result is identical to [powi_q8] with `n = 3`.
+///
+/// The square of magnitudes up to 2^14 fits into 32 bits
+/// and the cube is one 32x32 bit multiplication with [mul_shr32].
+/// Larger magnitudes are always clamped.
+#[inline(always)]
+pub(crate) fn cube_q8(q: i32) -> i32 {
+    let a = q.unsigned_abs();
+    let p = if a > 1 << 14 {
+        CLAMP
+    } else {
+        let (p, _) = mul_shr32((a * a) as i32, a as i32, 16, Round::Nearest);
+        p.min(CLAMP)
+    };
+    if q < 0 { -p } else { p }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -12,12 +12,37 @@
 //!
 //! - **Q7.8 format**: A 16-bit fixed-point number with 7 integer bits and 8 fractional bits [Q7p8].
 //! - **Q15.8 format**: A 24-bit fixed-point number with 15 integer bits and 8 fractional bits [Q15p8].
+//! - **Q15.16 format**: A 32-bit fixed-point number with 15 integer bits and 16 fractional bits [Q15p16].
+//! - **Q23.8 format**: A 32-bit fixed-point number with 23 integer bits and 8 fractional bits [Q23p8].
+//! - **UQ8.8 format**: A 16-bit unsigned fixed-point number with 8 integer bits and 8 fractional bits [UQ8p8].
+//! - **UQ16.8 format**: A 24-bit unsigned fixed-point number with 16 integer bits and 8 fractional bits [UQ16p8].
+//! - **Q0.15 format**: A 16-bit fractional number in the range [-1, 1) with 15 fractional bits [Q0p15].
+//! - **Q3.4 format**: An 8-bit fixed-point number with 3 integer bits and 4 fractional bits [Q3p4].
+//! - **Q0.7 format**: An 8-bit fractional number in the range [-1, 1) with 7 fractional bits [Q0p7].
+//! - **Generic format**: A fixed-point number with `i8`, `i16`, 24-bit or `i32` storage
+//!   and a const generic number of fractional bits [Q], for example Q3.12 as `Q<i16, 12>`.
 //!
 //! ## The supported operations are
 //!
 //! - Basic arithmetic operations: addition, subtraction, multiplication, and division.
+//! - Checked arithmetic operations returning `None` on overflow.
+//! - Overflowing arithmetic operations returning the saturated result and an overflow flag.
+//! - Multiplication and division with selectable rounding to nearest.
+//! - Fused multiply-add with a single rounding step ([Q7p8] and [Q15p8]).
+//! - Multiplication and division by `i8` and `i16` integers ([Q7p8] and [Q15p8]).
+//! - Mixed arithmetic operators between [Q7p8] and [Q15p8], resulting in [Q15p8].
+//! - Widening multiplication of two [Q7p8] values to [Q15p8] or [Q15p16].
+//! - Reciprocal and precomputed reciprocals for repeated divisions ([Reciprocal]).
+//! - Exact square root ([Q7p8] and [Q15p8]).
+//! - Sine and cosine of angles in radians ([Q7p8] and [Q15p8]).
+//! - CORDIC based `atan2`, `atan`, `asin` and `acos` ([Q7p8] and [Q15p8]).
+//! - Exponential, logarithm and power functions ([Q7p8] and [Q15p8]).
+//! - Integer powers, square and cube with a single final rounding ([Q7p8] and [Q15p8]).
+//! - Wrapping arithmetic operations and the [Wrapping] type.
 //! - Macros for easy construction of fixed-point numbers from integers or fractions.
 //! - Conversions between fixed-point types and integer types.
+//! - Rounding with `floor`, `ceil`, `round`, `trunc` and `fract` ([Q7p8] and [Q15p8]).
+//! - Const comparison and sign helpers such as `min`, `max`, `clamp`, `signum` and `copysign` ([Q7p8] and [Q15p8]).
 //! - Optional `curveipo` feature for curve interpolation.
 //!
 //! ## Creating fixed-point numbers
@@ -39,7 +64,7 @@
 //! let d = q7p8!(numerator / denominator);
 //! ```
 //!
-//! # Arithmetic operations
+//! ## Arithmetic operations
 //!
 //! ```
 //! use avr_q::{q7p8, Q7p8};
@@ -66,6 +91,16 @@
 //! assert_eq!(abs, q7p8!(const 1 / 2));
 //! ```
 //!
+//! ## Division by zero
+//!
+//! Division by zero never panics.
+//! All division variants, runtime and const alike, follow the same rules:
+//!
+//! - A non-negative dividend divided by zero, including zero divided by zero, saturates to the largest value.
+//! - A negative dividend divided by zero saturates to the smallest value.
+//! - The checked variants return `None`.
+//! - The overflowing variants return the saturated value and `true`.
+//!
 //! ## Crate features
 //!
 //! - `curveipo` (enabled by default):
@@ -73,11 +108,45 @@
 //!   [curveipo crate](https://crates.io/crates/curveipo)
 //!   for all fixed-point types.
 //!   The `curveipo` crate provides 2D curve interpolation support.
+//!
+//! - `saturation-flag` (disabled by default):
+//!   The `saturation-flag` feature enables a sticky saturation status flag.
+//!   The flag is set whenever a saturating operation or a `From` conversion clamps its result.
+//!   The `const fn` operations and conversions can't set the flag.
+//!   The application can read and clear the flag with `SaturationFlag`.
+//!   Without this feature the saturating operations have no overhead for the flag.
 
 #![cfg_attr(not(test), no_std)]
+#![cfg_attr(target_arch = "avr", feature(asm_experimental_arch))]
 
+#[cfg(not(target_arch = "avr"))]
+mod asm_generic;
+#[cfg(not(target_arch = "avr"))]
+use asm_generic as asm;
+
+#[cfg(target_arch = "avr")]
+mod asm_avr;
+#[cfg(target_arch = "avr")]
+use asm_avr as asm;
+
+mod cordic;
+mod exp;
+mod q;
+mod q0p15;
+mod q0p7;
+mod q15p16;
 mod q15p8;
+mod q23p8;
+mod q3p4;
 mod q7p8;
+mod recip;
+mod round;
+mod saturation;
+mod sqrt;
+mod trig;
+mod uq16p8;
+mod uq8p8;
+mod wrapping;
 
 #[cfg(feature = "curveipo")]
 mod curveipo;
@@ -85,7 +154,13 @@ mod curveipo;
 #[cfg(any(feature = "__internal_test__", test))]
 pub mod unit_tests;
 
-pub use crate::{q7p8::Q7p8, q15p8::Q15p8};
+pub use crate::{
+    q::Q, q0p7::Q0p7, q0p15::Q0p15, q3p4::Q3p4, q7p8::Q7p8, q15p8::Q15p8, q15p16::Q15p16,
+    q23p8::Q23p8, recip::Reciprocal, uq8p8::UQ8p8, uq16p8::UQ16p8, wrapping::Wrapping,
+};
+
+#[cfg(feature = "saturation-flag")]
+pub use crate::saturation::SaturationFlag;
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,964 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{round::Round, saturation::saturating};
+use avr_int24::I24;
+
+/// Generic fixed point number.
+///
+/// `S` is the storage type of the raw value.
+/// It is one of `i8`, `i16`, [I24] and `i32`.
+///
+/// `FRAC` is the length of the fractional part, in bits.
+/// It must be smaller than the number of bits of the storage type.
+///
+/// For example `Q<i16, 12>` is a Q3.12, `Q<i16, 14>` is a Q1.14
+/// and `Q<i16, 4>` is a Q11.4 fixed point number.
+///
+/// The formats `Q<i16, 8>` and `Q<I24, 8>` use optimized Q.8 implementations.
+/// [crate::Q7p8] and [crate::Q15p8] are thin wrappers around these formats
+/// and they convert losslessly to and from them.
+/// The multiplication of `Q<i16, 15>`, which is [crate::Q0p15],
+/// uses the AVR `FMULS` instruction family.
+///
+/// ```
+/// use avr_q::Q;
+///
+/// type Q3p12 = Q<i16, 12>;
+///
+/// let a = Q3p12::from_fraction(3, 2); // 1.5
+/// let b = Q3p12::from_int(2);
+/// assert_eq!(a * b, Q3p12::from_int(3));
+/// assert_eq!(a / b, Q3p12::from_fraction(3, 4));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[repr(transparent)]
+pub struct Q<S, const FRAC: usize>(S);
+
+/// Multiply two unsigned 32 bit values.
+/// Returns the lower and the upper 32 bits of the 64 bit product.
+///
+/// The product is composed of 16x16 bit partial products,
+/// so that no 64 bit arithmetic is needed.
+#[inline(always)]
+pub(crate) const fn umul32(a: u32, b: u32) -> (u32, u32) {
+    let (a1, a0) = (a >> 16, a & 0xFFFF);
+    let (b1, b0) = (b >> 16, b & 0xFFFF);
+    let (mid, carry) = (a1 * b0).overflowing_add(a0 * b1);
+    let hi = a1 * b1 + (mid >> 16) + ((carry as u32) << 16);
+    let (lo, carry) = (a0 * b0).overflowing_add(mid << 16);
+    (lo, hi + carry as u32)
+}
+
+/// Convert a magnitude and a sign to a 32 bit value.
+/// Returns the saturated value and `true`, if saturation happened.
+#[inline(always)]
+const fn from_mag32(neg: bool, m: u32) -> (i32, bool) {
+    if neg {
+        if m > 1 << 31 {
+            (i32::MIN, true)
+        } else {
+            ((m as i32).wrapping_neg(), false)
+        }
+    } else if m > i32::MAX as u32 {
+        (i32::MAX, true)
+    } else {
+        (m as i32, false)
+    }
+}
+
+/// Multiply two 32 bit values and right shift the product by `shift` bits.
+/// The shifted out bits are rounded according to `round`.
+/// Returns the product saturated to 32 bit and `true`, if saturation happened.
+#[inline(never)]
+pub(crate) const fn mul_shr32(a: i32, b: i32, shift: usize, round: Round) -> (i32, bool) {
+    let neg = (a < 0) != (b < 0);
+    let (lo, hi) = umul32(a.unsigned_abs(), b.unsigned_abs());
+    let (m, rem) = if shift == 0 {
+        (lo, 0)
+    } else {
+        (
+            (hi << (32 - shift)) | (lo >> shift),
+            lo & ((1 << shift) - 1),
+        )
+    };
+    let (m, carry) = m.overflowing_add(round.round_up(neg, m & 1 != 0, rem, 1 << shift) as u32);
+    if hi >> shift != 0 || carry {
+        // The magnitude doesn't fit into 32 bits.
+        (if neg { i32::MIN } else { i32::MAX }, true)
+    } else {
+        from_mag32(neg, m)
+    }
+}
+
+/// Multiply two 32 bit values and right shift the product by `shift` bits.
+/// The result wraps around on overflow.
+#[inline(never)]
+const fn wrapping_mul_shr32(a: i32, b: i32, shift: usize) -> i32 {
+    let (lo, hi) = umul32(a as u32, b as u32);
+    // Correct the upper half of the unsigned product to the signed product.
+    let hi = if a < 0 { hi.wrapping_sub(b as u32) } else { hi };
+    let hi = if b < 0 { hi.wrapping_sub(a as u32) } else { hi };
+    if shift == 0 {
+        lo as i32
+    } else {
+        ((hi << (32 - shift)) | (lo >> shift)) as i32
+    }
+}
+
+/// Left shift a 32 bit value by `shift` bits and divide it by another 32 bit value.
+/// The quotient is rounded according to `round`.
+/// Returns the quotient saturated to 32 bit and `true`, if saturation happened.
+/// Division by zero saturates, too.
+///
+/// The division is done bit by bit, so that no 64 bit arithmetic is needed.
+#[inline(never)]
+const fn shl_div32(a: i32, b: i32, shift: usize, round: Round) -> (i32, bool) {
+    if b == 0 {
+        return (if a < 0 { i32::MIN } else { i32::MAX }, true);
+    }
+    let neg = (a < 0) != (b < 0);
+    let sat = (if neg { i32::MIN } else { i32::MAX }, true);
+    let ua = a.unsigned_abs();
+    let ub = b.unsigned_abs();
+    // The upper bits of the 64 bit dividend are the initial remainder.
+    let (mut rem, mut lo) = if shift == 0 {
+        (0, ua)
+    } else {
+        (ua >> (32 - shift), ua << shift)
+    };
+    if rem >= ub {
+        // The quotient doesn't fit into 32 bits.
+        return sat;
+    }
+    let mut q: u32 = 0;
+    let mut i = 0;
+    while i < 32 {
+        // The remainder is smaller than the divisor, which is at most 2^31.
+        // Therefore, the shift doesn't overflow.
+        rem = (rem << 1) | (lo >> 31);
+        lo <<= 1;
+        q <<= 1;
+        if rem >= ub {
+            rem -= ub;
+            q |= 1;
+        }
+        i += 1;
+    }
+    let (q, carry) = q.overflowing_add(round.round_up(neg, q & 1 != 0, rem, ub) as u32);
+    if carry { sat } else { from_mag32(neg, q) }
+}
+
+/// Saturate a 32 bit value to 24 bit.
+/// Returns the saturated value and `true`, if saturation happened.
+#[inline(always)]
+const fn overflowing24((v, sat): (i32, bool)) -> (I24, bool) {
+    (I24::from_i32(v), sat || v < -0x80_0000 || v > 0x7F_FFFF)
+}
+
+/// Multiply two 24 bit values and right shift the product by `shift` bits.
+/// The shifted out bits are rounded according to `round`.
+/// Returns the product saturated to 24 bit and `true`, if saturation happened.
+#[inline(always)]
+const fn mul_shr24(a: i32, b: i32, shift: usize, round: Round) -> (I24, bool) {
+    overflowing24(mul_shr32(a, b, shift, round))
+}
+
+/// Multiply two 24 bit values and right shift the product by `shift` bits.
+/// The result wraps around on overflow.
+#[inline(always)]
+const fn wrapping_mul_shr24(a: i32, b: i32, shift: usize) -> I24 {
+    if shift <= 8 {
+        // The lower 32 bits of the product contain all bits of the wrapped result.
+        crate::q15p8::wrap24(a.wrapping_mul(b) >> shift)
+    } else {
+        crate::q15p8::wrap24(wrapping_mul_shr32(a, b, shift))
+    }
+}
+
+/// Left shift a 24 bit value by `shift` bits and divide it by another 24 bit value.
+/// The quotient is rounded according to `round`.
+/// Returns the quotient saturated to 24 bit and `true`, if saturation happened.
+/// Division by zero saturates, too.
+#[inline(always)]
+const fn shl_div24(a: i32, b: i32, shift: usize, round: Round) -> (I24, bool) {
+    overflowing24(shl_div32(a, b, shift, round))
+}
+
+/// Select the storage specific implementation, if there is one.
+/// Otherwise select the default implementation.
+macro_rules! select {
+    ([$specific:expr], $default:expr) => {
+        $specific
+    };
+    ([], $default:expr) => {
+        $default
+    };
+}
+
+macro_rules! impl_q {
+    (
+        storage: $storage:ident,
+        int: $int:ty,
+        sum: $sum:ty,
+        wide: $wide:ty,
+        bits: $bits:literal,
+        to: |$to_v:ident| $to:expr,
+        from: |$from_v:ident| $from:expr
+        $(, fast8: [$mul8:path, $mul_round8:path, $div8:path, $div_round8:path])?
+        $(, fmul: $fmul:path)?
+        $(, mul_shr: $mul_shr:path, wrapping_mul_shr: $wrapping_mul_shr:path, shl_div: $shl_div:path)?
+    ) => {
+        #[allow(clippy::should_implement_trait)]
+        impl<const FRAC: usize> Q<$storage, FRAC> {
+            /// Length of the fractional part, in bits.
+            pub const SHIFT: usize = {
+                assert!(FRAC < $bits, "FRAC must be smaller than the storage width");
+                FRAC
+            };
+
+            /// Smallest raw value.
+            const MIN_Q: $wide = -1 << ($bits - 1);
+
+            /// Largest raw value.
+            const MAX_Q: $wide = !Self::MIN_Q;
+
+            /// Convert a raw value to [Q].
+            pub const fn from_q(q: $storage) -> Self {
+                Self(q)
+            }
+
+            /// Convert an integer value to [Q] with fractional part being zero.
+            /// The value saturates, if the integer doesn't fit.
+            pub const fn from_int(int: $int) -> Self {
+                let int = int as $wide;
+                if int < Self::MIN_Q >> Self::SHIFT {
+                    Self::wrap(Self::MIN_Q)
+                } else if int > Self::MAX_Q >> Self::SHIFT {
+                    Self::wrap(Self::MAX_Q)
+                } else {
+                    Self::wrap(int << Self::SHIFT)
+                }
+            }
+
+            /// Convert a numerator/denominator fraction to [Q].
+            /// The quotient is truncated towards zero and saturated.
+            pub const fn from_fraction(numerator: $int, denominator: $int) -> Self {
+                select!(
+                    [$(Self($shl_div(numerator, denominator, Self::SHIFT, Round::Trunc).0))?],
+                    Self::const_overflowing_div_wide(
+                        (numerator as $wide) << Self::SHIFT,
+                        denominator as $wide,
+                    )
+                    .0
+                )
+            }
+
+            /// Convert this [Q] to a raw value.
+            pub const fn to_q(self) -> $storage {
+                self.0
+            }
+
+            /// Extract the integer part out of this [Q].
+            pub const fn to_int(self) -> $int {
+                (Self::to_wide(self.0) >> Self::SHIFT) as $int
+            }
+
+            /// Add and saturate two [Q] values.
+            #[inline(never)]
+            pub fn add(self, other: Self) -> Self {
+                saturating!(self.overflowing_add(other), self.const_add(other))
+            }
+
+            /// Add and saturate two [Q] values.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::add] instead.
+            pub const fn const_add(self, other: Self) -> Self {
+                self.overflowing_add(other).0
+            }
+
+            /// Subtract and saturate two [Q] values.
+            #[inline(never)]
+            pub fn sub(self, other: Self) -> Self {
+                saturating!(self.overflowing_sub(other), self.const_sub(other))
+            }
+
+            /// Subtract and saturate two [Q] values.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::sub] instead.
+            pub const fn const_sub(self, other: Self) -> Self {
+                self.overflowing_sub(other).0
+            }
+
+            /// Multiply and saturate two [Q] values.
+            #[inline(never)]
+            pub fn mul(self, other: Self) -> Self {
+                $(
+                    if FRAC == 8 {
+                        return Self(saturating!(
+                            $mul8(self.0, other.0),
+                            $mul8(self.0, other.0).0
+                        ));
+                    }
+                )?
+                $(
+                    if FRAC == 15 {
+                        return Self(saturating!(
+                            $fmul(self.0, other.0, Round::Floor),
+                            $fmul(self.0, other.0, Round::Floor).0
+                        ));
+                    }
+                )?
+                saturating!(self.const_overflowing_mul(other), self.const_mul(other))
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::mul] instead.
+            pub const fn const_mul(self, other: Self) -> Self {
+                self.const_overflowing_mul(other).0
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// Division by zero saturates, too.
+            #[inline(never)]
+            pub fn div(self, other: Self) -> Self {
+                $(
+                    if FRAC == 8 {
+                        return Self(saturating!(
+                            $div8(self.0, other.0),
+                            $div8(self.0, other.0).0
+                        ));
+                    }
+                )?
+                saturating!(self.const_overflowing_div(other), self.const_div(other))
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// Division by zero saturates, too.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::div] instead.
+            pub const fn const_div(self, other: Self) -> Self {
+                self.const_overflowing_div(other).0
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// The product is rounded to nearest, with ties rounded away from zero.
+            #[inline(never)]
+            pub fn mul_round(self, other: Self) -> Self {
+                $(
+                    if FRAC == 8 {
+                        return Self(saturating!(
+                            $mul_round8(self.0, other.0, Round::Nearest),
+                            $mul_round8(self.0, other.0, Round::Nearest).0
+                        ));
+                    }
+                )?
+                $(
+                    if FRAC == 15 {
+                        return Self(saturating!(
+                            $fmul(self.0, other.0, Round::Nearest),
+                            $fmul(self.0, other.0, Round::Nearest).0
+                        ));
+                    }
+                )?
+                saturating!(
+                    self.const_overflowing_mul_rounded(other, Round::Nearest),
+                    self.const_mul_round(other)
+                )
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// The product is rounded to nearest, with ties rounded away from zero.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::mul_round] instead.
+            pub const fn const_mul_round(self, other: Self) -> Self {
+                self.const_overflowing_mul_rounded(other, Round::Nearest).0
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// The product is rounded to nearest, with ties rounded to even.
+            #[inline(never)]
+            pub fn mul_round_even(self, other: Self) -> Self {
+                $(
+                    if FRAC == 8 {
+                        return Self(saturating!(
+                            $mul_round8(self.0, other.0, Round::NearestEven),
+                            $mul_round8(self.0, other.0, Round::NearestEven).0
+                        ));
+                    }
+                )?
+                $(
+                    if FRAC == 15 {
+                        return Self(saturating!(
+                            $fmul(self.0, other.0, Round::NearestEven),
+                            $fmul(self.0, other.0, Round::NearestEven).0
+                        ));
+                    }
+                )?
+                saturating!(
+                    self.const_overflowing_mul_rounded(other, Round::NearestEven),
+                    self.const_mul_round_even(other)
+                )
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// The product is rounded to nearest, with ties rounded to even.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::mul_round_even] instead.
+            pub const fn const_mul_round_even(self, other: Self) -> Self {
+                self.const_overflowing_mul_rounded(other, Round::NearestEven).0
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// The quotient is rounded to nearest, with ties rounded away from zero.
+            #[inline(never)]
+            pub fn div_round(self, other: Self) -> Self {
+                $(
+                    if FRAC == 8 {
+                        return Self(saturating!(
+                            $div_round8(self.0, other.0, Round::Nearest),
+                            $div_round8(self.0, other.0, Round::Nearest).0
+                        ));
+                    }
+                )?
+                saturating!(
+                    self.const_overflowing_div_rounded(other, Round::Nearest),
+                    self.const_div_round(other)
+                )
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// The quotient is rounded to nearest, with ties rounded away from zero.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::div_round] instead.
+            pub const fn const_div_round(self, other: Self) -> Self {
+                self.const_overflowing_div_rounded(other, Round::Nearest).0
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// The quotient is rounded to nearest, with ties rounded to even.
+            #[inline(never)]
+            pub fn div_round_even(self, other: Self) -> Self {
+                $(
+                    if FRAC == 8 {
+                        return Self(saturating!(
+                            $div_round8(self.0, other.0, Round::NearestEven),
+                            $div_round8(self.0, other.0, Round::NearestEven).0
+                        ));
+                    }
+                )?
+                saturating!(
+                    self.const_overflowing_div_rounded(other, Round::NearestEven),
+                    self.const_div_round_even(other)
+                )
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// The quotient is rounded to nearest, with ties rounded to even.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::div_round_even] instead.
+            pub const fn const_div_round_even(self, other: Self) -> Self {
+                self.const_overflowing_div_rounded(other, Round::NearestEven).0
+            }
+
+            /// Negate and saturate this [Q] value.
+            #[inline(never)]
+            pub fn neg(self) -> Self {
+                saturating!(self.overflowing_neg(), self.const_neg())
+            }
+
+            /// Negate and saturate this [Q] value.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::neg] instead.
+            pub const fn const_neg(self) -> Self {
+                self.overflowing_neg().0
+            }
+
+            /// Get the absolute and saturated value of this [Q].
+            #[inline(never)]
+            pub fn abs(self) -> Self {
+                saturating!(self.overflowing_abs(), self.const_abs())
+            }
+
+            /// Get the absolute and saturated value of this [Q].
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::abs] instead.
+            pub const fn const_abs(self) -> Self {
+                self.overflowing_abs().0
+            }
+
+            /// Add two [Q] values.
+            /// The result wraps around on overflow.
+            pub const fn wrapping_add(self, other: Self) -> Self {
+                Self::wrap(Self::to_wide(self.0).wrapping_add(Self::to_wide(other.0)))
+            }
+
+            /// Subtract two [Q] values.
+            /// The result wraps around on overflow.
+            pub const fn wrapping_sub(self, other: Self) -> Self {
+                Self::wrap(Self::to_wide(self.0).wrapping_sub(Self::to_wide(other.0)))
+            }
+
+            /// Multiply two [Q] values.
+            /// The result wraps around on overflow.
+            pub const fn wrapping_mul(self, other: Self) -> Self {
+                select!(
+                    [$(Self($wrapping_mul_shr(
+                        Self::to_wide(self.0),
+                        Self::to_wide(other.0),
+                        Self::SHIFT,
+                    )))?],
+                    Self::wrap((Self::to_wide(self.0) * Self::to_wide(other.0)) >> Self::SHIFT)
+                )
+            }
+
+            /// Divide two [Q] values.
+            /// The quotient is truncated towards zero and wraps around on overflow.
+            /// Division by zero saturates.
+            pub const fn wrapping_div(self, other: Self) -> Self {
+                let a = Self::to_wide(self.0);
+                let b = Self::to_wide(other.0);
+                if b == 0 {
+                    self.const_div(other)
+                } else if $bits <= 16 {
+                    // The shifted dividend of at most 16 bits fits into 32 bits.
+                    Self::wrap((((a as i32) << Self::SHIFT) / b as i32) as $wide)
+                } else {
+                    Self::wrap((((a as i64) << Self::SHIFT) / b as i64) as $wide)
+                }
+            }
+
+            /// Negate this [Q] value.
+            /// The result wraps around on overflow.
+            pub const fn wrapping_neg(self) -> Self {
+                Self::wrap(Self::to_wide(self.0).wrapping_neg())
+            }
+
+            /// Add two [Q] values.
+            /// Returns `None` on overflow.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                Self::checked(self.overflowing_add(other))
+            }
+
+            /// Subtract two [Q] values.
+            /// Returns `None` on overflow.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                Self::checked(self.overflowing_sub(other))
+            }
+
+            /// Multiply two [Q] values.
+            /// Returns `None` on overflow.
+            #[inline(never)]
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                Self::checked(self.overflowing_mul(other))
+            }
+
+            /// Multiply two [Q] values.
+            /// Returns `None` on overflow.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::checked_mul] instead.
+            pub const fn const_checked_mul(self, other: Self) -> Option<Self> {
+                Self::checked(self.const_overflowing_mul(other))
+            }
+
+            /// Divide two [Q] values.
+            /// Returns `None` on overflow or division by zero.
+            #[inline(never)]
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                Self::checked(self.overflowing_div(other))
+            }
+
+            /// Divide two [Q] values.
+            /// Returns `None` on overflow or division by zero.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::checked_div] instead.
+            pub const fn const_checked_div(self, other: Self) -> Option<Self> {
+                Self::checked(self.const_overflowing_div(other))
+            }
+
+            /// Negate this [Q] value.
+            /// Returns `None` on overflow.
+            pub const fn checked_neg(self) -> Option<Self> {
+                Self::checked(self.overflowing_neg())
+            }
+
+            /// Get the absolute value of this [Q].
+            /// Returns `None` on overflow.
+            pub const fn checked_abs(self) -> Option<Self> {
+                Self::checked(self.overflowing_abs())
+            }
+
+            /// Add and saturate two [Q] values.
+            /// Returns the saturated sum and `true`, if saturation happened.
+            pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+                let b = Self::to_sum(other.0);
+                Self::const_overflowing_from_sum(Self::to_sum(self.0).checked_add(b), b < 0)
+            }
+
+            /// Subtract and saturate two [Q] values.
+            /// Returns the saturated difference and `true`, if saturation happened.
+            pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+                let b = Self::to_sum(other.0);
+                Self::const_overflowing_from_sum(Self::to_sum(self.0).checked_sub(b), b > 0)
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// Returns the saturated product and `true`, if saturation happened.
+            #[inline(never)]
+            pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+                $(
+                    if FRAC == 8 {
+                        let (c, sat) = $mul8(self.0, other.0);
+                        return (Self(c), sat);
+                    }
+                )?
+                $(
+                    if FRAC == 15 {
+                        let (c, sat) = $fmul(self.0, other.0, Round::Floor);
+                        return (Self(c), sat);
+                    }
+                )?
+                self.const_overflowing_mul(other)
+            }
+
+            /// Multiply and saturate two [Q] values.
+            /// Returns the saturated product and `true`, if saturation happened.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::overflowing_mul] instead.
+            pub const fn const_overflowing_mul(self, other: Self) -> (Self, bool) {
+                self.const_overflowing_mul_rounded(other, Round::Floor)
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// Returns the saturated quotient and `true`, if saturation happened.
+            /// Division by zero saturates, too.
+            #[inline(never)]
+            pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+                $(
+                    if FRAC == 8 {
+                        let (c, sat) = $div8(self.0, other.0);
+                        return (Self(c), sat);
+                    }
+                )?
+                self.const_overflowing_div(other)
+            }
+
+            /// Divide and saturate two [Q] values.
+            /// Returns the saturated quotient and `true`, if saturation happened.
+            /// Division by zero saturates, too.
+            /// Const variant.
+            ///
+            /// Only call this function from const context.
+            /// From non-const context use the optimized variant [Q::overflowing_div] instead.
+            pub const fn const_overflowing_div(self, other: Self) -> (Self, bool) {
+                select!(
+                    [$({
+                        let a = Self::to_wide(self.0);
+                        let b = Self::to_wide(other.0);
+                        let (c, sat) = $shl_div(a, b, Self::SHIFT, Round::Trunc);
+                        (Self(c), sat)
+                    })?],
+                    Self::const_overflowing_div_wide(
+                        Self::to_wide(self.0) << Self::SHIFT,
+                        Self::to_wide(other.0),
+                    )
+                )
+            }
+
+            /// Multiply, round and saturate two [Q] values in const context.
+            const fn const_overflowing_mul_rounded(self, other: Self, round: Round) -> (Self, bool) {
+                select!(
+                    [$({
+                        let a = Self::to_wide(self.0);
+                        let b = Self::to_wide(other.0);
+                        let (c, sat) = $mul_shr(a, b, Self::SHIFT, round);
+                        (Self(c), sat)
+                    })?],
+                    {
+                        // The product of two raw values always fits into the wide type.
+                        let p = Self::to_wide(self.0) * Self::to_wide(other.0);
+                        let c = match round {
+                            Round::Floor => p >> Self::SHIFT,
+                            _ => round.shr32(p as i32, Self::SHIFT) as $wide,
+                        };
+                        Self::const_overflowing_from_wide(c)
+                    }
+                )
+            }
+
+            /// Divide, round and saturate two [Q] values in const context.
+            const fn const_overflowing_div_rounded(self, other: Self, round: Round) -> (Self, bool) {
+                select!(
+                    [$({
+                        let a = Self::to_wide(self.0);
+                        let b = Self::to_wide(other.0);
+                        let (c, sat) = $shl_div(a, b, Self::SHIFT, round);
+                        (Self(c), sat)
+                    })?],
+                    {
+                        let a = Self::to_wide(self.0);
+                        let b = Self::to_wide(other.0);
+                        if b == 0 {
+                            self.const_overflowing_div(other)
+                        } else {
+                            // The shifted dividend of at most 16 bits fits into 32 bits.
+                            let c = round.div32((a as i32) << Self::SHIFT, b as i32);
+                            Self::const_overflowing_from_wide(c as $wide)
+                        }
+                    }
+                )
+            }
+
+            /// Divide a wide dividend by a wide divisor.
+            /// The quotient is truncated towards zero.
+            /// Returns the saturated quotient and `true`, if saturation happened.
+            #[allow(dead_code)] // Not used by all storage types.
+            const fn const_overflowing_div_wide(a: $wide, b: $wide) -> (Self, bool) {
+                if b == 0 {
+                    let c = if a < 0 { Self::MIN_Q } else { Self::MAX_Q };
+                    (Self::wrap(c), true)
+                } else {
+                    Self::const_overflowing_from_wide(a / b)
+                }
+            }
+
+            /// Convert and saturate a wide raw value to [Q].
+            /// Returns the saturated value and `true`, if saturation happened.
+            const fn const_overflowing_from_wide(v: $wide) -> (Self, bool) {
+                if v < Self::MIN_Q {
+                    (Self::wrap(Self::MIN_Q), true)
+                } else if v > Self::MAX_Q {
+                    (Self::wrap(Self::MAX_Q), true)
+                } else {
+                    (Self::wrap(v), false)
+                }
+            }
+
+            /// Negate and saturate this [Q] value.
+            /// Returns the saturated value and `true`, if saturation happened.
+            const fn overflowing_neg(self) -> (Self, bool) {
+                Self::const_overflowing_from_sum(Self::to_sum(self.0).checked_neg(), false)
+            }
+
+            /// Get the absolute and saturated value of this [Q].
+            /// Returns the saturated value and `true`, if saturation happened.
+            const fn overflowing_abs(self) -> (Self, bool) {
+                Self::const_overflowing_from_sum(Self::to_sum(self.0).checked_abs(), false)
+            }
+
+            /// Convert and saturate a raw sum to [Q].
+            /// `None` is a sum that overflowed the sum type,
+            /// towards negative infinity if `neg` is `true`.
+            /// Returns the saturated value and `true`, if saturation happened.
+            const fn const_overflowing_from_sum(v: Option<$sum>, neg: bool) -> (Self, bool) {
+                match v {
+                    None => (Self::wrap(if neg { Self::MIN_Q } else { Self::MAX_Q }), true),
+                    Some(v) if v < Self::MIN_Q as $sum => (Self::wrap(Self::MIN_Q), true),
+                    Some(v) if v > Self::MAX_Q as $sum => (Self::wrap(Self::MAX_Q), true),
+                    Some(v) => (Self::wrap(v as $wide), false),
+                }
+            }
+
+            /// Convert a saturated value to `None`, if saturation happened.
+            const fn checked((v, sat): (Self, bool)) -> Option<Self> {
+                if sat { None } else { Some(v) }
+            }
+
+            /// Convert a raw value to the sum type.
+            #[inline(always)]
+            const fn to_sum($to_v: $storage) -> $sum {
+                $to as $sum
+            }
+
+            /// Convert a raw value to the wide type.
+            #[inline(always)]
+            const fn to_wide($to_v: $storage) -> $wide {
+                $to as $wide
+            }
+
+            /// Truncate a wide value to a raw value.
+            /// The upper bits are discarded, so that the value wraps around.
+            #[inline(always)]
+            const fn wrap($from_v: $wide) -> Self {
+                Self($from)
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::Add for Q<$storage, FRAC> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::add(self, other)
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::AddAssign for Q<$storage, FRAC> {
+            fn add_assign(&mut self, other: Self) {
+                self.0 = (*self + other).0;
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::Sub for Q<$storage, FRAC> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self::sub(self, other)
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::SubAssign for Q<$storage, FRAC> {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 = (*self - other).0;
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::Mul for Q<$storage, FRAC> {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self::mul(self, other)
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::MulAssign for Q<$storage, FRAC> {
+            fn mul_assign(&mut self, other: Self) {
+                self.0 = (*self * other).0;
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::Div for Q<$storage, FRAC> {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                Self::div(self, other)
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::DivAssign for Q<$storage, FRAC> {
+            fn div_assign(&mut self, other: Self) {
+                self.0 = (*self / other).0;
+            }
+        }
+
+        impl<const FRAC: usize> core::ops::Neg for Q<$storage, FRAC> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::neg(self)
+            }
+        }
+    };
+}
+
+impl_q!(
+    storage: i8,
+    int: i8,
+    sum: i16,
+    wide: i16,
+    bits: 8,
+    to: |v| v,
+    from: |v| v as i8
+);
+
+impl_q!(
+    storage: i16,
+    int: i16,
+    sum: i32,
+    wide: i32,
+    bits: 16,
+    to: |v| v,
+    from: |v| v as i16,
+    fast8: [
+        crate::q7p8::mul_q7p8,
+        crate::q7p8::mul_q7p8_round,
+        crate::q7p8::div_q7p8,
+        crate::q7p8::div_q7p8_round
+    ],
+    fmul: crate::q0p15::mul_q0p15
+);
+
+impl_q!(
+    storage: I24,
+    int: i32,
+    sum: i32,
+    wide: i32,
+    bits: 24,
+    to: |v| v.to_i32(),
+    from: |v| crate::q15p8::wrap24(v),
+    fast8: [
+        crate::q15p8::mul24_shr8_floor,
+        crate::q15p8::mul24_shr8,
+        crate::q15p8::shl8div24,
+        crate::q15p8::shl8div24_round
+    ],
+    mul_shr: mul_shr24,
+    wrapping_mul_shr: wrapping_mul_shr24,
+    shl_div: shl_div24
+);
+
+impl_q!(
+    storage: i32,
+    int: i32,
+    sum: i32,
+    wide: i32,
+    bits: 32,
+    to: |v| v,
+    from: |v| v as i32,
+    mul_shr: mul_shr32,
+    wrapping_mul_shr: wrapping_mul_shr32,
+    shl_div: shl_div32
+);
+
+impl From<crate::Q7p8> for Q<i16, 8> {
+    fn from(value: crate::Q7p8) -> Self {
+        Self(value.to_q())
+    }
+}
+
+impl From<Q<i16, 8>> for crate::Q7p8 {
+    fn from(value: Q<i16, 8>) -> Self {
+        Self::from_q(value.to_q())
+    }
+}
+
+impl From<crate::Q15p8> for Q<I24, 8> {
+    fn from(value: crate::Q15p8) -> Self {
+        Self(value.to_q())
+    }
+}
+
+impl From<Q<I24, 8>> for crate::Q15p8 {
+    fn from(value: Q<I24, 8>) -> Self {
+        Self::from_q(value.to_q())
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,164 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Q, asm::asm_fmuls16, round::Round, saturation::saturating};
+use avr_int24::I24;
+
+/// Q0.15 fixed point number.
+///
+/// This is a fractional number in the range [-1, 1) with 15 fractional bits.
+/// It is also known as Q1.15, if the sign bit is counted.
+///
+/// All operations of the generic [Q] type are available.
+/// The multiplication uses the AVR `FMULS` instruction family.
+///
+/// ```
+/// use avr_q::{q0p15, Q0p15};
+///
+/// let a = q0p15!(const 1 / 2);
+/// let b = q0p15!(const -1 / 4);
+/// assert_eq!(a * b, q0p15!(const -1 / 8));
+/// assert_eq!(q0p15!(const -1) * q0p15!(const -1), Q0p15::from_q(i16::MAX));
+/// ```
+pub type Q0p15 = Q<i16, 15>;
+
+/// Construct a Q0.15 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the Q0.15 value and the fractional part is zero.
+///
+/// `q0p15!(-1)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `q0p15!(3 / 10)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `q0p15!(const 3 / 10)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! q0p15 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::Q0p15::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::Q0p15::from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::Q0p15::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::Q0p15::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::Q0p15::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::Q0p15::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::Q0p15::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::Q0p15::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::Q0p15::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::Q0p15::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::Q0p15::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::Q0p15::from_int($numerator)
+    };
+}
+
+/// Multiply two raw Q0.15 values.
+/// The product is rounded according to `round`.
+/// Returns the saturated product and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn mul_q0p15(a: i16, b: i16, round: Round) -> (i16, bool) {
+    if a == i16::MIN && b == i16::MIN {
+        // -1 * -1 is the only product that doesn't fit.
+        return (i16::MAX, true);
+    }
+    // The Q0.15 product is the upper half of the fractional product.
+    let p = asm_fmuls16(a, b);
+    if round == Round::Floor {
+        return ((p >> 16) as i16, false);
+    }
+    let neg = p < 0;
+    let m = p.unsigned_abs();
+    let q = m >> 16;
+    let q = q + round.round_up(neg, q & 1 != 0, m & 0xFFFF, 0x1_0000) as u32;
+    // The rounded magnitude is at most 0x8000 for negative products
+    // and at most 0x7FFF for positive products.
+    let q = if neg { -(q as i32) } else { q as i32 };
+    (q as i16, false)
+}
+
+/// Convert a raw Q.8 value to [Q0p15].
+/// The value saturates, if it doesn't fit.
+/// Returns the saturated value and `true`, if saturation happened.
+pub(crate) const fn q0p15_from_q8(q: i32) -> (Q0p15, bool) {
+    let q = q << 7;
+    if q > i16::MAX as i32 {
+        (Q0p15::from_q(i16::MAX), true)
+    } else if q < i16::MIN as i32 {
+        (Q0p15::from_q(i16::MIN), true)
+    } else {
+        (Q0p15::from_q(q as i16), false)
+    }
+}
+
+impl Q0p15 {
+    /// Convert this [Q0p15] to a [crate::Q7p8].
+    /// The lower fractional bits are rounded towards negative infinity.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::from_q(self.to_q() >> 7)
+    }
+
+    /// Convert this [Q0p15] to a [crate::Q15p8].
+    /// The lower fractional bits are rounded towards negative infinity.
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i16(self.to_q() >> 7))
+    }
+}
+
+impl From<crate::Q7p8> for Q0p15 {
+    fn from(v: crate::Q7p8) -> Q0p15 {
+        saturating!(q0p15_from_q8(v.to_q() as i32), v.to_q0p15())
+    }
+}
+
+impl From<crate::Q15p8> for Q0p15 {
+    fn from(v: crate::Q15p8) -> Q0p15 {
+        saturating!(q0p15_from_q8(v.to_q().to_i32()), v.to_q0p15())
+    }
+}
+
+impl From<Q0p15> for crate::Q7p8 {
+    fn from(v: Q0p15) -> crate::Q7p8 {
+        v.to_q7p8()
+    }
+}
+
+impl From<Q0p15> for crate::Q15p8 {
+    fn from(v: Q0p15) -> crate::Q15p8 {
+        v.to_q15p8()
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,145 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Q, saturation::saturating};
+use avr_int24::I24;
+
+/// Q0.7 fixed point number.
+///
+/// This is an 8 bit fractional number in the range [-1, 1) with 7 fractional bits.
+/// It is intended for compact lookup tables and state arrays.
+/// Computations on larger values should be done after widening to
+/// [crate::Q7p8] or [crate::Q15p8], which is lossless.
+///
+/// All operations of the generic [Q] type are available.
+/// The arithmetic operations saturate.
+///
+/// ```
+/// use avr_q::{q0p7, q7p8, Q0p7, Q7p8};
+///
+/// let a = q0p7!(const 1 / 2);
+/// let b = q0p7!(const -1 / 4);
+/// assert_eq!(a * b, q0p7!(const -1 / 8));
+/// assert_eq!(q0p7!(const -1) * q0p7!(const -1), Q0p7::from_q(i8::MAX));
+/// assert_eq!(Q7p8::from(a), q7p8!(const 1 / 2));
+/// assert_eq!(Q0p7::from(q7p8!(const 3)), Q0p7::from_q(i8::MAX));
+/// ```
+pub type Q0p7 = Q<i8, 7>;
+
+/// Construct a Q0.7 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the Q0.7 value and the fractional part is zero.
+///
+/// `q0p7!(-1)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `q0p7!(3 / 10)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `q0p7!(const 3 / 10)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! q0p7 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::Q0p7::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::Q0p7::from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::Q0p7::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::Q0p7::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::Q0p7::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::Q0p7::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::Q0p7::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::Q0p7::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::Q0p7::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::Q0p7::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::Q0p7::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::Q0p7::from_int($numerator)
+    };
+}
+
+/// Convert a raw Q.8 value to [Q0p7].
+/// The lower fractional bits are rounded towards negative infinity.
+/// The value saturates, if it doesn't fit.
+/// Returns the saturated value and `true`, if saturation happened.
+pub(crate) const fn q0p7_from_q8(q: i32) -> (Q0p7, bool) {
+    let q = q >> 1;
+    if q > i8::MAX as i32 {
+        (Q0p7::from_q(i8::MAX), true)
+    } else if q < i8::MIN as i32 {
+        (Q0p7::from_q(i8::MIN), true)
+    } else {
+        (Q0p7::from_q(q as i8), false)
+    }
+}
+
+impl Q0p7 {
+    /// Convert this [Q0p7] to a [crate::Q7p8].
+    /// The conversion is lossless.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::from_q((self.to_q() as i16) << 1)
+    }
+
+    /// Convert this [Q0p7] to a [crate::Q15p8].
+    /// The conversion is lossless.
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i16((self.to_q() as i16) << 1))
+    }
+}
+
+impl From<crate::Q7p8> for Q0p7 {
+    fn from(v: crate::Q7p8) -> Q0p7 {
+        saturating!(q0p7_from_q8(v.to_q() as i32), v.to_q0p7())
+    }
+}
+
+impl From<crate::Q15p8> for Q0p7 {
+    fn from(v: crate::Q15p8) -> Q0p7 {
+        saturating!(q0p7_from_q8(v.to_q().to_i32()), v.to_q0p7())
+    }
+}
+
+impl From<Q0p7> for crate::Q7p8 {
+    fn from(v: Q0p7) -> crate::Q7p8 {
+        v.to_q7p8()
+    }
+}
+
+impl From<Q0p7> for crate::Q15p8 {
+    fn from(v: Q0p7) -> crate::Q15p8 {
+        v.to_q15p8()
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,149 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Q, saturation::saturating};
+use avr_int24::I24;
+
+/// Q15.16 fixed point number.
+///
+/// This is a 32 bit number with 15 integer bits and 16 fractional bits.
+///
+/// All operations of the generic [Q] type are available.
+/// The multiplication and the division don't need 64 bit arithmetic.
+///
+/// ```
+/// use avr_q::{q15p16, Q15p16};
+///
+/// let a = q15p16!(const 1000);
+/// let b = q15p16!(const 1 / 4);
+/// assert_eq!(a * b, q15p16!(const 250));
+/// assert_eq!(a / q15p16!(const 8), q15p16!(const 125));
+/// assert_eq!(q15p16!(const 1 / 3), Q15p16::from_q(0x5555));
+/// ```
+pub type Q15p16 = Q<i32, 16>;
+
+/// Construct a Q15.16 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the Q15.16 value and the fractional part is zero.
+///
+/// `q15p16!(42)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `q15p16!(42 / 10)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `q15p16!(const 42 / 10)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! q15p16 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::Q15p16::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::Q15p16::from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::Q15p16::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::Q15p16::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::Q15p16::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::Q15p16::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::Q15p16::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::Q15p16::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::Q15p16::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::Q15p16::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::Q15p16::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::Q15p16::from_int($numerator)
+    };
+}
+
+/// Convert a raw Q.8 value to [Q15p16].
+/// The value saturates, if it doesn't fit.
+/// Returns the saturated value and `true`, if saturation happened.
+pub(crate) const fn q15p16_from_q8(q: i32) -> (Q15p16, bool) {
+    if q < i32::MIN >> 8 {
+        (Q15p16::from_q(i32::MIN), true)
+    } else if q > i32::MAX >> 8 {
+        (Q15p16::from_q(i32::MAX), true)
+    } else {
+        (Q15p16::from_q(q << 8), false)
+    }
+}
+
+impl Q15p16 {
+    /// Convert this [Q15p16] to a [crate::Q7p8].
+    /// The lower fractional bits are rounded towards negative infinity.
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::const_overflowing_from_i32(self.to_q() >> 8).0
+    }
+
+    /// Convert this [Q15p16] to a [crate::Q15p8].
+    /// The lower fractional bits are rounded towards negative infinity.
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i32(self.to_q() >> 8))
+    }
+
+    /// Convert this [Q15p16] to a [crate::Q23p8].
+    /// The lower fractional bits are rounded towards negative infinity.
+    pub const fn to_q23p8(&self) -> crate::Q23p8 {
+        crate::Q23p8::from_q(self.to_q() >> 8)
+    }
+}
+
+impl From<crate::Q7p8> for Q15p16 {
+    fn from(v: crate::Q7p8) -> Q15p16 {
+        v.to_q15p16()
+    }
+}
+
+impl From<crate::Q15p8> for Q15p16 {
+    fn from(v: crate::Q15p8) -> Q15p16 {
+        v.to_q15p16()
+    }
+}
+
+impl From<Q15p16> for crate::Q7p8 {
+    fn from(v: Q15p16) -> crate::Q7p8 {
+        saturating!(
+            crate::Q7p8::const_overflowing_from_i32(v.to_q() >> 8),
+            v.to_q7p8()
+        )
+    }
+}
+
+impl From<Q15p16> for crate::Q15p8 {
+    fn from(v: Q15p16) -> crate::Q15p8 {
+        v.to_q15p8()
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
+use crate::{Q, round::Round, saturation::saturating};
 use avr_int24::I24;
 
 /// Q15.8 fixed point number.
+///
+/// This is a thin wrapper around `Q<I24, 8>` with additional Q15.8 specific operations.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[repr(transparent)]
-pub struct Q15p8(I24);
+pub struct Q15p8(Q<I24, 8>);
 
 /// Construct a Q15.8 fixed point number.
 ///
@@ -71,6 +74,231 @@ macro_rules! q15p8 {
     };
 }
 
+/// Raw 24 bit limits.
+const I24_MIN: i32 = -0x80_0000;
+const I24_MAX: i32 = 0x7F_FFFF;
+const MIN24: I24 = I24::from_i32(I24_MIN);
+const MAX24: I24 = I24::from_i32(I24_MAX);
+
+/// Mask of the fractional bits of a raw Q15.8 value.
+const FRAC_MASK: i32 = (1 << Q15p8::SHIFT) - 1;
+
+/// Check if a 32 bit value is outside of the 24 bit range.
+#[inline(always)]
+const fn exceeds24(v: i32) -> bool {
+    v < I24_MIN || v > I24_MAX
+}
+
+/// Truncate a 32 bit value to 24 bit.
+/// The upper bits are discarded, so that the value wraps around.
+#[inline(always)]
+pub(crate) const fn wrap24(v: i32) -> I24 {
+    let v = v.to_le_bytes();
+    I24::from_le_bytes([v[0], v[1], v[2]])
+}
+
+/// Multiply two 24 bit values and right shift the product by 8 bits.
+/// The shifted out bits are rounded according to `round`.
+/// Returns the product saturated to 24 bit and `true`, if saturation happened.
+///
+/// The full 48 bit product is never materialized.
+/// Instead the magnitudes are split into 8 bit limbs,
+/// so that only 16x16 bit multiplications are needed.
+#[inline(always)]
+pub(crate) fn mul24_shr8(a: I24, b: I24, round: Round) -> (I24, bool) {
+    let a = a.to_i32();
+    let b = b.to_i32();
+    let neg = (a ^ b) < 0;
+    let a = a.unsigned_abs();
+    let b = b.unsigned_abs();
+
+    let (ah, al) = ((a >> 8) as u16, (a & 0xFF) as u16);
+    let (bh, bl) = ((b >> 8) as u16, (b & 0xFF) as u16);
+
+    let hi = ah as u32 * bh as u32;
+    if hi > 0x8000 {
+        // The product is out of range for 24 bit.
+        return if neg {
+            (I24::from_i32(I24_MIN), true)
+        } else {
+            (I24::from_i32(I24_MAX), true)
+        };
+    }
+    let mid = (ah as u32 * bl as u32) + (al as u32 * bh as u32);
+    let lo = al as u32 * bl as u32;
+
+    let c = (hi << 8) + mid + (lo >> 8);
+    let c = c + round.round_up(neg, c & 1 != 0, lo & 0xFF, 0x100) as u32;
+    let c = if neg { -(c as i32) } else { c as i32 };
+    (I24::from_i32(c), exceeds24(c))
+}
+
+/// Multiply two 24 bit values and right shift the product by 8 bits.
+/// The shifted out bits are rounded towards negative infinity.
+/// Returns the product saturated to 24 bit and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn mul24_shr8_floor(a: I24, b: I24) -> (I24, bool) {
+    mul24_shr8(a, b, Round::Floor)
+}
+
+/// Multiply a 24 bit value by a 16 bit integer.
+/// Returns the product saturated to 24 bit and `true`, if saturation happened.
+///
+/// The magnitude of the 24 bit value is split into 8 bit limbs,
+/// so that only 16x16 bit multiplications are needed.
+#[inline(always)]
+fn mul_int24(a: I24, int: i16) -> (I24, bool) {
+    let a = a.to_i32();
+    let neg = (a < 0) != (int < 0);
+    let a = a.unsigned_abs();
+    let b = int.unsigned_abs() as u32;
+
+    let hi = (a >> 8) * b;
+    if hi > 0x8000 {
+        // The product is out of range for 24 bit.
+        return if neg { (MIN24, true) } else { (MAX24, true) };
+    }
+    let c = (hi << 8) + (a & 0xFF) * b;
+    let c = if neg { -(c as i32) } else { c as i32 };
+    (I24::from_i32(c), exceeds24(c))
+}
+
+/// Divide a 24 bit value by a 16 bit integer.
+/// Returns the quotient saturated to 24 bit and `true`, if saturation happened.
+#[inline(always)]
+fn div_int24(a: I24, int: i16) -> (I24, bool) {
+    if int == 0 {
+        (if a.to_i32() < 0 { MIN24 } else { MAX24 }, true)
+    } else if int == -1 && a == MIN24 {
+        (MAX24, true)
+    } else if a == MIN24 {
+        // The 24 bit division can't take the magnitude of the minimum.
+        // Use the 32 bit division instead.
+        (I24::from_i32(a.to_i32() / int as i32), false)
+    } else {
+        (a / I24::from_i16(int), false)
+    }
+}
+
+/// Divide a 16 bit integer by a 24 bit value.
+/// Returns the quotient saturated to 24 bit and `true`, if saturation happened.
+#[inline(always)]
+fn int_div24(int: i16, b: I24) -> (I24, bool) {
+    let b = b.to_i32();
+    match ((int as i32) << 16).checked_div(b) {
+        Some(q) => (I24::from_i32(q), exceeds24(q)),
+        // Division by zero or MIN / -1.
+        None => (if int < 0 && b == 0 { MIN24 } else { MAX24 }, true),
+    }
+}
+
+/// Multiply two 24 bit values, right shift the product by 8 bits and add a third 24 bit value.
+/// The exact result is rounded once according to `round`.
+/// Returns the result saturated to 24 bit and `true`, if saturation happened.
+///
+/// Like in [mul24_shr8] the full 48 bit product is never materialized.
+/// The shifted product is kept as an integer part and 8 fractional bits
+/// until after the addition.
+#[inline(always)]
+fn mul_add24_shr8(a: I24, b: I24, c: I24, round: Round) -> (I24, bool) {
+    let a = a.to_i32();
+    let b = b.to_i32();
+    let neg = (a ^ b) < 0;
+    let a = a.unsigned_abs();
+    let b = b.unsigned_abs();
+
+    let (ah, al) = ((a >> 8) as u16, (a & 0xFF) as u16);
+    let (bh, bl) = ((b >> 8) as u16, (b & 0xFF) as u16);
+
+    let hi = ah as u32 * bh as u32;
+    if hi > 0x1_0000 {
+        // The magnitude of the shifted product is bigger than 2^24.
+        // The addend can't move the sum back into the 24 bit range.
+        return if neg { (MIN24, true) } else { (MAX24, true) };
+    }
+    let mid = (ah as u32 * bl as u32) + (al as u32 * bh as u32);
+    let lo = al as u32 * bl as u32;
+    let m = (hi << 8) + mid + (lo >> 8);
+    let frac = lo & 0xFF;
+
+    // Convert the shifted product to an integer part rounded towards
+    // negative infinity and a non-negative fractional part.
+    let (int, frac) = match (neg, frac) {
+        (false, _) => (m as i32, frac),
+        (true, 0) => (-(m as i32), 0),
+        (true, _) => (-(m as i32) - 1, 0x100 - frac),
+    };
+    // The magnitude of the integer part is less than 2^25.
+    // Therefore, the sum can't overflow.
+    let s = int + c.to_i32();
+
+    // Round the magnitude of the exact sum.
+    let neg = s < 0;
+    let (q, rem) = match (neg, frac) {
+        (false, _) => (s as u32, frac),
+        (true, 0) => ((-s) as u32, 0),
+        (true, _) => ((-s - 1) as u32, 0x100 - frac),
+    };
+    let q = q + round.round_up(neg, q & 1 != 0, rem, 0x100) as u32;
+    let q = if neg { -(q as i32) } else { q as i32 };
+    (I24::from_i32(q), exceeds24(q))
+}
+
+/// Left shift a 24 bit value by 8 bits and divide it by another 24 bit value.
+/// Returns the quotient saturated to 24 bit and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn shl8div24(a: I24, b: I24) -> (I24, bool) {
+    let a32 = a.to_i32();
+    let b32 = b.to_i32();
+    let neg = (a32 < 0) != (b32 < 0);
+    let ua = a32.unsigned_abs();
+    let ub = b32.unsigned_abs();
+    if ub < 0x200 && ua >= ub << 15 {
+        // The magnitude of the quotient is at least 2^23.
+        // This includes division by zero.
+        // Only a negative quotient of exactly -2^23 is representable.
+        let sat = !neg || (ua - (ub << 15)) << 8 >= ub;
+        (if neg { MIN24 } else { MAX24 }, sat)
+    } else if b32 == I24_MIN {
+        // The division can't take the magnitude of the minimum.
+        let c = (ua >> 15) as i32;
+        (I24::from_i32(if neg { -c } else { c }), false)
+    } else if a32 == I24_MIN {
+        // The division can't take the magnitude of the minimum.
+        // The divisor is large here, so the 32 bit division doesn't overflow.
+        (I24::from_i32(i32::MIN / b32), false)
+    } else {
+        // The quotient is in range and the division doesn't saturate.
+        (a.shl8div(b), false)
+    }
+}
+
+/// Left shift a 24 bit value by 8 bits and divide it by another 24 bit value.
+/// The quotient is rounded according to `round`.
+/// Returns the quotient saturated to 24 bit and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn shl8div24_round(a: I24, b: I24, round: Round) -> (I24, bool) {
+    let (q, sat) = shl8div24(a, b);
+    if sat {
+        // Rounding can't move the quotient back into range.
+        return (q, true);
+    }
+    let a = a.to_i32();
+    let b = b.to_i32();
+    let q32 = q.to_i32();
+    // The remainder of the truncating division fits into 24 bits.
+    // Therefore, the lower 32 bits of the calculation are sufficient.
+    let rem = (a << 8).wrapping_sub(q32.wrapping_mul(b));
+    let neg = (a < 0) != (b < 0);
+    let odd = q32 & 1 != 0;
+    if round.round_up(neg, odd, rem.unsigned_abs(), b.unsigned_abs()) {
+        let c = if neg { q32 - 1 } else { q32 + 1 };
+        (I24::from_i32(c), exceeds24(c))
+    } else {
+        (q, false)
+    }
+}
+
 #[allow(clippy::should_implement_trait)]
 impl Q15p8 {
     /// Length of the fractional part, in bits.
@@ -78,7 +306,7 @@ impl Q15p8 {
 
     /// Convert a raw Q15.8 value to [Q15p8].
     pub const fn from_q(q: I24) -> Self {
-        Self(q)
+        Self(Q::<I24, 8>::from_q(q))
     }
 
     /// Convert an integer value to [Q15p8] with fractional part being zero.
@@ -86,12 +314,12 @@ impl Q15p8 {
         const {
             assert!(Self::SHIFT == 8);
         }
-        Self(I24::from_i16(int).shl8())
+        Self::from_q(I24::from_i16(int).shl8())
     }
 
     /// Convert a numerator/denominator fraction to [Q15p8].
     pub fn from_fraction(numerator: i16, denominator: i16) -> Self {
-        Self(I24::from_i16(numerator)) / Self(I24::from_i16(denominator))
+        Self::from_q(I24::from_i16(numerator)) / Self::from_q(I24::from_i16(denominator))
     }
 
     /// Convert a numerator/denominator fraction to [Q15p8].
@@ -100,30 +328,125 @@ impl Q15p8 {
     /// Only call this function from const context.
     /// From non-const context use the optimized variant [Q15p8::from_fraction] instead.
     pub const fn const_from_fraction(numerator: i16, denominator: i16) -> Self {
-        Self(I24::from_i16(numerator)).const_div(Self(I24::from_i16(denominator)))
+        Self::from_q(I24::from_i16(numerator)).const_div(Self::from_q(I24::from_i16(denominator)))
     }
 
     /// Convert this [Q15p8] to a raw Q15.8 value.
     pub const fn to_q(self) -> I24 {
-        self.0
+        self.0.to_q()
     }
 
     /// Extract the integer part out of this [Q15p8].
+    /// The value is rounded towards negative infinity, like [Q15p8::floor].
     pub const fn to_int(self) -> i16 {
         const {
             assert!(Self::SHIFT == 8);
         }
-        self.0.shr8().to_i16()
+        self.to_q().shr8().to_i16()
+    }
+
+    /// Round this [Q15p8] to the nearest integer, with ties rounded away from zero,
+    /// and convert it to an integer.
+    /// The result saturates.
+    pub const fn to_int_round(self) -> i16 {
+        self.round().to_int()
+    }
+
+    /// Round this [Q15p8] towards positive infinity and convert it to an integer.
+    /// The result saturates.
+    pub const fn to_int_ceil(self) -> i16 {
+        self.ceil().to_int()
+    }
+
+    /// Round this [Q15p8] towards zero and convert it to an integer.
+    pub const fn to_int_trunc(self) -> i16 {
+        self.trunc().to_int()
+    }
+
+    /// Round this [Q15p8] towards negative infinity to an integral value.
+    pub const fn floor(self) -> Self {
+        Self::from_q(I24::from_i32(self.to_q().to_i32() & !FRAC_MASK))
+    }
+
+    /// Round this [Q15p8] towards positive infinity to an integral value.
+    /// The result saturates.
+    pub const fn ceil(self) -> Self {
+        Self::from_q(I24::from_i32(
+            (self.to_q().to_i32() + FRAC_MASK) & !FRAC_MASK,
+        ))
+    }
+
+    /// Round this [Q15p8] to the nearest integral value, with ties rounded away from zero.
+    /// The result saturates.
+    pub const fn round(self) -> Self {
+        let q = self.to_q().to_i32();
+        let m = (q.abs() + (1 << (Self::SHIFT - 1))) & !FRAC_MASK;
+        Self::from_q(I24::from_i32(if q < 0 { -m } else { m }))
+    }
+
+    /// Round this [Q15p8] towards zero to an integral value.
+    pub const fn trunc(self) -> Self {
+        let q = self.to_q().to_i32();
+        Self::from_q(I24::from_i32(q - q % (1 << Self::SHIFT)))
+    }
+
+    /// Get the fractional part of this [Q15p8].
+    /// This is `self - self.trunc()`, which has the same sign as `self`.
+    pub const fn fract(self) -> Self {
+        Self::from_q(I24::from_i32(self.to_q().to_i32() % (1 << Self::SHIFT)))
     }
 
     /// Convert this [Q15p8] to a [crate::Q7p8].
     pub const fn to_q7p8(&self) -> crate::Q7p8 {
-        crate::Q7p8::from_q(self.0.to_i16())
+        crate::Q7p8::from_q(self.to_q().to_i16())
+    }
+
+    /// Convert this [Q15p8] to a [crate::Q15p16].
+    pub const fn to_q15p16(&self) -> crate::Q15p16 {
+        crate::Q15p16::from_q(self.to_q().to_i32() << 8)
+    }
+
+    /// Convert this [Q15p8] to a [crate::Q23p8].
+    pub const fn to_q23p8(&self) -> crate::Q23p8 {
+        crate::Q23p8::from_q(self.to_q().to_i32())
+    }
+
+    /// Convert this [Q15p8] to a [crate::Q0p15].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q0p15(&self) -> crate::Q0p15 {
+        crate::q0p15::q0p15_from_q8(self.to_q().to_i32()).0
+    }
+
+    /// Convert this [Q15p8] to a [crate::Q3p4].
+    /// The lower fractional bits are rounded towards negative infinity.
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q3p4(&self) -> crate::Q3p4 {
+        crate::q3p4::q3p4_from_q8(self.to_q().to_i32()).0
+    }
+
+    /// Convert this [Q15p8] to a [crate::Q0p7].
+    /// The lower fractional bits are rounded towards negative infinity.
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q0p7(&self) -> crate::Q0p7 {
+        crate::q0p7::q0p7_from_q8(self.to_q().to_i32()).0
+    }
+
+    /// Convert this [Q15p8] to a [crate::UQ8p8].
+    /// The value saturates at zero and at the largest value, if it doesn't fit.
+    pub const fn to_uq8p8(&self) -> crate::UQ8p8 {
+        crate::uq8p8::uq8p8_from_q8(self.to_q().to_i32()).0
+    }
+
+    /// Convert this [Q15p8] to a [crate::UQ16p8].
+    /// Negative values saturate at zero.
+    pub const fn to_uq16p8(&self) -> crate::UQ16p8 {
+        crate::uq16p8::uq16p8_from_q8(self.to_q().to_i32()).0
     }
 
     /// Add and saturate two [Q15p8] values.
+    #[inline(always)]
     pub fn add(self, other: Self) -> Self {
-        Self(self.0 + other.0)
+        Self(self.0.add(other.0))
     }
 
     /// Add and saturate two [Q15p8] values.
@@ -136,8 +459,9 @@ impl Q15p8 {
     }
 
     /// Subtract and saturate two [Q15p8] values.
+    #[inline(always)]
     pub fn sub(self, other: Self) -> Self {
-        Self(self.0 - other.0)
+        Self(self.0.sub(other.0))
     }
 
     /// Subtract and saturate two [Q15p8] values.
@@ -149,35 +473,533 @@ impl Q15p8 {
         Self(self.0.const_sub(other.0))
     }
 
-    //TODO mul
+    /// Multiply and saturate two [Q15p8] values.
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        Self(self.0.mul(other.0))
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::mul] instead.
+    pub const fn const_mul(self, other: Self) -> Self {
+        Self(self.0.const_mul(other.0))
+    }
 
     /// Divide and saturate two [Q15p8] values.
+    /// Division by zero saturates, too.
+    #[inline(always)]
     pub fn div(self, other: Self) -> Self {
-        const {
-            assert!(Self::SHIFT == 8);
-        }
-        Self(self.0.shl8div(other.0))
+        Self(self.0.div(other.0))
     }
 
     /// Divide and saturate two [Q15p8] values.
+    /// Division by zero saturates, too.
     /// Const variant.
     ///
     /// Only call this function from const context.
     /// From non-const context use the optimized variant [Q15p8::div] instead.
     pub const fn const_div(self, other: Self) -> Self {
-        let a = self.0.to_i32();
-        let b = other.0.to_i32();
-        let c = if b == 0 {
-            if a < 0 { i32::MIN } else { i32::MAX }
-        } else {
-            (a << Self::SHIFT).saturating_div(b)
-        };
-        Self(I24::from_i32(c))
+        Self(self.0.const_div(other.0))
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    #[inline(always)]
+    pub fn mul_round(self, other: Self) -> Self {
+        Self(self.0.mul_round(other.0))
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::mul_round] instead.
+    pub const fn const_mul_round(self, other: Self) -> Self {
+        Self(self.0.const_mul_round(other.0))
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    #[inline(always)]
+    pub fn mul_round_even(self, other: Self) -> Self {
+        Self(self.0.mul_round_even(other.0))
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::mul_round_even] instead.
+    pub const fn const_mul_round_even(self, other: Self) -> Self {
+        Self(self.0.const_mul_round_even(other.0))
+    }
+
+    /// Divide and saturate two [Q15p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    #[inline(always)]
+    pub fn div_round(self, other: Self) -> Self {
+        Self(self.0.div_round(other.0))
+    }
+
+    /// Divide and saturate two [Q15p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::div_round] instead.
+    pub const fn const_div_round(self, other: Self) -> Self {
+        Self(self.0.const_div_round(other.0))
+    }
+
+    /// Divide and saturate two [Q15p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    #[inline(always)]
+    pub fn div_round_even(self, other: Self) -> Self {
+        Self(self.0.div_round_even(other.0))
+    }
+
+    /// Divide and saturate two [Q15p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::div_round_even] instead.
+    pub const fn const_div_round_even(self, other: Self) -> Self {
+        Self(self.0.const_div_round_even(other.0))
+    }
+
+    /// Calculate the reciprocal `1 / self` and saturate.
+    ///
+    /// The result is exact and identical to `q15p8!(1) / self`,
+    /// which is rounded towards zero.
+    /// It is calculated with a table seed and Newton-Raphson refinement,
+    /// which is faster than the full division on AVR.
+    /// Division by zero and tiny values saturate.
+    ///
+    /// To divide several values by the same divisor,
+    /// use a [crate::Reciprocal] of the divisor.
+    #[inline(never)]
+    pub fn recip(self) -> Self {
+        let q = crate::recip::recip_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(q), exceeds24(q)),
+            I24::from_i32(q)
+        ))
+    }
+
+    /// Divide this [Q15p8] value by a precomputed [crate::Reciprocal] and saturate.
+    /// See [crate::Reciprocal] for the error bound.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_reciprocal(self, divisor: crate::Reciprocal) -> Self {
+        let q = divisor.div_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(q), exceeds24(q)),
+            I24::from_i32(q)
+        ))
+    }
+
+    /// Multiply this [Q15p8] value by an integer and saturate.
+    /// The product is exact, unless it saturates.
+    #[inline(never)]
+    pub fn mul_int(self, int: i16) -> Self {
+        Self::from_q(saturating!(
+            mul_int24(self.to_q(), int),
+            mul_int24(self.to_q(), int).0
+        ))
+    }
+
+    /// Divide this [Q15p8] value by an integer and saturate.
+    /// The quotient is rounded towards zero.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_int(self, int: i16) -> Self {
+        Self::from_q(saturating!(
+            div_int24(self.to_q(), int),
+            div_int24(self.to_q(), int).0
+        ))
+    }
+
+    /// Multiply two [Q15p8] values and add a third [Q15p8] value.
+    /// This calculates `self * factor + addend` with only one rounding step.
+    /// The exact result is rounded to nearest, with ties rounded away from zero,
+    /// and saturated afterwards.
+    #[inline(never)]
+    pub fn mul_add(self, factor: Self, addend: Self) -> Self {
+        Self::from_q(saturating!(
+            mul_add24_shr8(self.to_q(), factor.to_q(), addend.to_q(), Round::Nearest),
+            mul_add24_shr8(self.to_q(), factor.to_q(), addend.to_q(), Round::Nearest).0
+        ))
+    }
+
+    /// Multiply two [Q15p8] values and add a third [Q15p8] value.
+    /// This calculates `self * factor + addend` with only one rounding step.
+    /// The exact result is rounded to nearest, with ties rounded away from zero,
+    /// and saturated afterwards.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::mul_add] instead.
+    pub const fn const_mul_add(self, factor: Self, addend: Self) -> Self {
+        let a = self.to_q().to_i32() as i64;
+        let b = factor.to_q().to_i32() as i64;
+        let c = addend.to_q().to_i32() as i64;
+        let p = a * b + (c << Self::SHIFT);
+        Self::const_overflowing_from_i64(Round::Nearest.const_div(p, 1 << Self::SHIFT)).0
+    }
+
+    /// Calculate the square root of this [Q15p8] value.
+    /// The square root is exact and rounded towards zero.
+    /// The square root of a negative value saturates to zero.
+    #[inline(never)]
+    pub fn sqrt(self) -> Self {
+        let (r, neg) = crate::sqrt::sqrt_q8(self.to_q().to_i32(), 16);
+        Self::from_q(saturating!((I24::from_i32(r), neg), I24::from_i32(r)))
+    }
+
+    /// Calculate the square root of this [Q15p8] value.
+    /// The square root is exact and rounded towards zero.
+    /// The square root of a negative value saturates to zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::sqrt] instead.
+    pub const fn const_sqrt(self) -> Self {
+        Self::from_q(I24::from_i32(
+            crate::sqrt::sqrt_q8(self.to_q().to_i32(), 16).0,
+        ))
+    }
+
+    /// Calculate the square root of this [Q15p8] value.
+    /// The square root is exact and rounded towards zero.
+    /// Returns `None`, if the value is negative.
+    pub const fn checked_sqrt(self) -> Option<Self> {
+        match crate::sqrt::sqrt_q8(self.to_q().to_i32(), 16) {
+            (r, false) => Some(Self::from_q(I24::from_i32(r))),
+            (_, true) => None,
+        }
+    }
+
+    /// Calculate the sine of this [Q15p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact sine.
+    #[inline(never)]
+    pub fn sin(self) -> Self {
+        Self::from_q(I24::from_i16(crate::trig::sin_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the sine of this [Q15p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact sine.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::sin] instead.
+    pub const fn const_sin(self) -> Self {
+        Self::from_q(I24::from_i16(crate::trig::sin_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the cosine of this [Q15p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact cosine.
+    #[inline(never)]
+    pub fn cos(self) -> Self {
+        Self::from_q(I24::from_i16(crate::trig::cos_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the cosine of this [Q15p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact cosine.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::cos] instead.
+    pub const fn const_cos(self) -> Self {
+        Self::from_q(I24::from_i16(crate::trig::cos_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the four-quadrant arctangent of `self` (y) and `other` (x) in radians.
+    /// The result is in the range `[-pi, pi]` and within 0.6 LSB of the exact angle.
+    /// The arctangent of two zeros is zero.
+    #[inline(never)]
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_q(crate::cordic::atan2_i24(self.to_q(), other.to_q()))
+    }
+
+    /// Calculate the four-quadrant arctangent of `self` (y) and `other` (x) in radians.
+    /// The result is in the range `[-pi, pi]` and within 0.6 LSB of the exact angle.
+    /// The arctangent of two zeros is zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::atan2] instead.
+    pub const fn const_atan2(self, other: Self) -> Self {
+        Self::from_q(crate::cordic::const_atan2_i24(self.to_q(), other.to_q()))
+    }
+
+    /// Calculate the arctangent of this [Q15p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    #[inline(never)]
+    pub fn atan(self) -> Self {
+        Self::from_q(crate::cordic::atan2_i24(self.to_q(), I24::from_i32(1 << 8)))
+    }
+
+    /// Calculate the arctangent of this [Q15p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::atan] instead.
+    pub const fn const_atan(self) -> Self {
+        Self::from_q(crate::cordic::const_atan2_i24(
+            self.to_q(),
+            I24::from_i32(1 << 8),
+        ))
+    }
+
+    /// Calculate the arcsine of this [Q15p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    #[inline(never)]
+    pub fn asin(self) -> Self {
+        let (a, clamped) = crate::cordic::asin_i24(self.to_q());
+        let a = Self::from_q(a);
+        saturating!((a, clamped), a)
+    }
+
+    /// Calculate the arcsine of this [Q15p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::asin] instead.
+    pub const fn const_asin(self) -> Self {
+        Self::from_q(crate::cordic::const_asin_i24(self.to_q()))
+    }
+
+    /// Calculate the arccosine of this [Q15p8] value in radians.
+    /// The result is in the range `[0, pi]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    #[inline(never)]
+    pub fn acos(self) -> Self {
+        let (a, clamped) = crate::cordic::acos_i24(self.to_q());
+        let a = Self::from_q(a);
+        saturating!((a, clamped), a)
+    }
+
+    /// Calculate the arccosine of this [Q15p8] value in radians.
+    /// The result is in the range `[0, pi]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::acos] instead.
+    pub const fn const_acos(self) -> Self {
+        Self::from_q(crate::cordic::const_acos_i24(self.to_q()))
+    }
+
+    /// Calculate the exponential function `e^self` of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    #[inline(never)]
+    pub fn exp(self) -> Self {
+        let r = crate::exp::exp_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r)),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Calculate the exponential function `e^self` of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::exp] instead.
+    pub const fn const_exp(self) -> Self {
+        Self::from_q(I24::from_i32(crate::exp::exp_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the power of two `2^self` of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    #[inline(never)]
+    pub fn exp2(self) -> Self {
+        let r = crate::exp::exp2_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r)),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Calculate the power of two `2^self` of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::exp2] instead.
+    pub const fn const_exp2(self) -> Self {
+        Self::from_q(I24::from_i32(crate::exp::exp2_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the natural logarithm of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    #[inline(never)]
+    pub fn ln(self) -> Self {
+        let r = crate::exp::ln_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r)),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Calculate the natural logarithm of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::ln] instead.
+    pub const fn const_ln(self) -> Self {
+        Self::from_q(I24::from_i32(crate::exp::ln_q8(self.to_q().to_i32())))
+    }
+
+    /// Calculate the base 2 logarithm of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    #[inline(never)]
+    pub fn log2(self) -> Self {
+        let r = crate::exp::log2_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r)),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Calculate the base 2 logarithm of this [Q15p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::log2] instead.
+    pub const fn const_log2(self) -> Self {
+        Self::from_q(I24::from_i32(crate::exp::log2_q8(self.to_q().to_i32())))
+    }
+
+    /// Raise this [Q15p8] value to the power of `exponent` and saturate.
+    /// The error is less than 0.5 LSB plus a relative error of
+    /// `2^-23 * max(1, |exponent|)`.
+    ///
+    /// A zero base results in zero for positive exponents, in one for a zero exponent
+    /// and saturates for negative exponents.
+    /// A negative base saturates to zero.
+    #[inline(never)]
+    pub fn pow(self, exponent: Self) -> Self {
+        let (r, neg) = crate::exp::pow_q8(self.to_q().to_i32(), exponent.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r) || neg),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Raise this [Q15p8] value to the power of `exponent` and saturate.
+    /// The error is less than 0.5 LSB plus a relative error of
+    /// `2^-23 * max(1, |exponent|)`.
+    ///
+    /// A zero base results in zero for positive exponents, in one for a zero exponent
+    /// and saturates for negative exponents.
+    /// A negative base saturates to zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::pow] instead.
+    pub const fn const_pow(self, exponent: Self) -> Self {
+        Self::from_q(I24::from_i32(
+            crate::exp::pow_q8(self.to_q().to_i32(), exponent.to_q().to_i32()).0,
+        ))
+    }
+
+    /// Raise this [Q15p8] value to the integer power `n` and saturate.
+    /// The power is calculated by exponentiation by squaring with a wide intermediate
+    /// and it is rounded to nearest, with ties rounded away from zero.
+    /// The result is exact for `n <= 4`.
+    /// For larger `n` the result is within 0.5 LSB plus a relative error of `2^-54`.
+    /// Zero to the power of zero is one.
+    #[inline(never)]
+    pub fn powi(self, n: u8) -> Self {
+        match n {
+            2 => return self.square(),
+            3 => return self.cube(),
+            _ => (),
+        }
+        let r = crate::exp::powi_q8(self.to_q().to_i32(), n);
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r)),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Raise this [Q15p8] value to the integer power `n` and saturate.
+    /// The power is calculated by exponentiation by squaring with a wide intermediate
+    /// and it is rounded to nearest, with ties rounded away from zero.
+    /// The result is exact for `n <= 4`.
+    /// For larger `n` the result is within 0.5 LSB plus a relative error of `2^-54`.
+    /// Zero to the power of zero is one.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::powi] instead.
+    pub const fn const_powi(self, n: u8) -> Self {
+        Self::from_q(I24::from_i32(crate::exp::const_powi_q8(
+            self.to_q().to_i32(),
+            n,
+        )))
+    }
+
+    /// Square this [Q15p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    #[inline(never)]
+    pub fn square(self) -> Self {
+        self.mul_round(self)
+    }
+
+    /// Square this [Q15p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::square] instead.
+    pub const fn const_square(self) -> Self {
+        self.const_powi(2)
+    }
+
+    /// Cube this [Q15p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    #[inline(never)]
+    pub fn cube(self) -> Self {
+        let r = crate::exp::cube_q8(self.to_q().to_i32());
+        Self::from_q(saturating!(
+            (I24::from_i32(r), exceeds24(r)),
+            I24::from_i32(r)
+        ))
+    }
+
+    /// Cube this [Q15p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::cube] instead.
+    pub const fn const_cube(self) -> Self {
+        self.const_powi(3)
     }
 
     /// Negate and saturate this [Q15p8] value.
+    #[inline(always)]
     pub fn neg(self) -> Self {
-        Self(-self.0)
+        Self(self.0.neg())
     }
 
     /// Negate and saturate this [Q15p8] value.
@@ -190,6 +1012,7 @@ impl Q15p8 {
     }
 
     /// Get the absolute and saturated value of this [Q15p8].
+    #[inline(always)]
     pub fn abs(self) -> Self {
         Self(self.0.abs())
     }
@@ -202,6 +1025,254 @@ impl Q15p8 {
     pub const fn const_abs(self) -> Self {
         Self(self.0.const_abs())
     }
+
+    /// Get the minimum of two [Q15p8] values.
+    pub const fn min(self, other: Self) -> Self {
+        if self.to_q().to_i32() <= other.to_q().to_i32() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Get the maximum of two [Q15p8] values.
+    pub const fn max(self, other: Self) -> Self {
+        if self.to_q().to_i32() >= other.to_q().to_i32() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict this [Q15p8] value to the range `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `min` is bigger than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.to_q().to_i32() <= max.to_q().to_i32(), "min > max");
+        self.max(min).min(max)
+    }
+
+    /// Get the sign of this [Q15p8] value.
+    /// Returns `-1`, `0` or `1`.
+    pub const fn signum(self) -> Self {
+        let q = self.to_q().to_i32();
+        Self::from_q(I24::from_i32(
+            ((q > 0) as i32 - (q < 0) as i32) << Self::SHIFT,
+        ))
+    }
+
+    /// Check whether this [Q15p8] value is smaller than zero.
+    pub const fn is_negative(self) -> bool {
+        self.to_q().to_i32() < 0
+    }
+
+    /// Check whether this [Q15p8] value is bigger than zero.
+    pub const fn is_positive(self) -> bool {
+        self.to_q().to_i32() > 0
+    }
+
+    /// Check whether this [Q15p8] value is zero.
+    pub const fn is_zero(self) -> bool {
+        self.to_q().to_i32() == 0
+    }
+
+    /// Get the magnitude of this [Q15p8] value with the sign of `sign`.
+    /// Zero is treated as positive.
+    /// The magnitude of the minimum value saturates, if `sign` is not negative.
+    pub const fn copysign(self, sign: Self) -> Self {
+        let q = self.to_q().to_i32();
+        // m is all ones, if the signs differ.
+        // (q ^ m) - m then is the negation of q.
+        let m = (q ^ sign.to_q().to_i32()) >> 31;
+        Self::from_q(I24::from_i32((q ^ m) - m))
+    }
+
+    /// Get the absolute difference of two [Q15p8] values.
+    /// The result is exact.
+    pub const fn abs_diff(self, other: Self) -> crate::UQ16p8 {
+        crate::UQ16p8::from_q(self.to_q().to_i32().abs_diff(other.to_q().to_i32()))
+    }
+
+    /// Add two [Q15p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+
+    /// Subtract two [Q15p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+
+    /// Multiply two [Q15p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        Self(self.0.wrapping_mul(other.0))
+    }
+
+    /// Divide two [Q15p8] values.
+    /// The quotient is truncated towards zero and wraps around on overflow.
+    /// Division by zero saturates.
+    pub const fn wrapping_div(self, other: Self) -> Self {
+        Self(self.0.wrapping_div(other.0))
+    }
+
+    /// Negate this [Q15p8] value.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Add two [Q15p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Subtract two [Q15p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Multiply two [Q15p8] values.
+    /// Returns `None` on overflow.
+    #[inline(always)]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.0.checked_mul(other.0).map(Self)
+    }
+
+    /// Multiply two [Q15p8] values.
+    /// Returns `None` on overflow.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::checked_mul] instead.
+    pub const fn const_checked_mul(self, other: Self) -> Option<Self> {
+        match self.0.const_checked_mul(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Divide two [Q15p8] values.
+    /// Returns `None` on overflow or division by zero.
+    #[inline(always)]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.0.checked_div(other.0).map(Self)
+    }
+
+    /// Divide two [Q15p8] values.
+    /// Returns `None` on overflow or division by zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::checked_div] instead.
+    pub const fn const_checked_div(self, other: Self) -> Option<Self> {
+        match self.0.const_checked_div(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Negate this [Q15p8] value.
+    /// Returns `None` on overflow.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.0.checked_neg() {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Get the absolute value of this [Q15p8].
+    /// Returns `None` on overflow.
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.0.checked_abs() {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Add and saturate two [Q15p8] values.
+    /// Returns the saturated sum and `true`, if saturation happened.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_add(other.0);
+        (Self(c), sat)
+    }
+
+    /// Subtract and saturate two [Q15p8] values.
+    /// Returns the saturated difference and `true`, if saturation happened.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_sub(other.0);
+        (Self(c), sat)
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    #[inline(always)]
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_mul(other.0);
+        (Self(c), sat)
+    }
+
+    /// Multiply and saturate two [Q15p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::overflowing_mul] instead.
+    pub const fn const_overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.const_overflowing_mul(other.0);
+        (Self(c), sat)
+    }
+
+    /// Divide and saturate two [Q15p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    #[inline(always)]
+    pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_div(other.0);
+        (Self(c), sat)
+    }
+
+    /// Divide and saturate two [Q15p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q15p8::overflowing_div] instead.
+    pub const fn const_overflowing_div(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.const_overflowing_div(other.0);
+        (Self(c), sat)
+    }
+
+    /// Convert and saturate a 32 bit raw value to [Q15p8].
+    /// Returns the saturated value and `true`, if saturation happened.
+    pub(crate) const fn const_overflowing_from_i32(v: i32) -> (Self, bool) {
+        (Self::from_q(I24::from_i32(v)), exceeds24(v))
+    }
+
+    /// Convert and saturate a wide raw value to [Q15p8].
+    /// Returns the saturated value and `true`, if saturation happened.
+    const fn const_overflowing_from_i64(v: i64) -> (Self, bool) {
+        if v < I24_MIN as i64 {
+            (Self::from_q(I24::from_i32(I24_MIN)), true)
+        } else if v > I24_MAX as i64 {
+            (Self::from_q(I24::from_i32(I24_MAX)), true)
+        } else {
+            (Self::from_q(I24::from_i32(v as i32)), false)
+        }
+    }
 }
 
 impl From<u8> for Q15p8 {
@@ -234,6 +1305,21 @@ impl From<crate::Q7p8> for Q15p8 {
     }
 }
 
+impl From<crate::UQ8p8> for Q15p8 {
+    fn from(v: crate::UQ8p8) -> Q15p8 {
+        v.to_q15p8()
+    }
+}
+
+impl From<crate::UQ16p8> for Q15p8 {
+    fn from(v: crate::UQ16p8) -> Q15p8 {
+        saturating!(
+            Self::const_overflowing_from_i32(v.to_q() as i32),
+            v.to_q15p8()
+        )
+    }
+}
+
 impl core::ops::Add for Q15p8 {
     type Output = Self;
 
@@ -244,7 +1330,7 @@ impl core::ops::Add for Q15p8 {
 
 impl core::ops::AddAssign for Q15p8 {
     fn add_assign(&mut self, other: Self) {
-        self.0 = (*self + other).0;
+        *self = *self + other;
     }
 }
 
@@ -258,7 +1344,21 @@ impl core::ops::Sub for Q15p8 {
 
 impl core::ops::SubAssign for Q15p8 {
     fn sub_assign(&mut self, other: Self) {
-        self.0 = (*self - other).0;
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Mul for Q15p8 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(self, other)
+    }
+}
+
+impl core::ops::MulAssign for Q15p8 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
     }
 }
 
@@ -272,7 +1372,106 @@ impl core::ops::Div for Q15p8 {
 
 impl core::ops::DivAssign for Q15p8 {
     fn div_assign(&mut self, other: Self) {
-        self.0 = (*self / other).0;
+        *self = *self / other;
+    }
+}
+
+macro_rules! impl_int_ops {
+    ($int:ty) => {
+        impl core::ops::Mul<$int> for Q15p8 {
+            type Output = Self;
+
+            fn mul(self, other: $int) -> Self {
+                Self::mul_int(self, other as i16)
+            }
+        }
+
+        impl core::ops::MulAssign<$int> for Q15p8 {
+            fn mul_assign(&mut self, other: $int) {
+                *self = *self * other;
+            }
+        }
+
+        impl core::ops::Mul<Q15p8> for $int {
+            type Output = Q15p8;
+
+            fn mul(self, other: Q15p8) -> Q15p8 {
+                Q15p8::mul_int(other, self as i16)
+            }
+        }
+
+        impl core::ops::Div<$int> for Q15p8 {
+            type Output = Self;
+
+            fn div(self, other: $int) -> Self {
+                Self::div_int(self, other as i16)
+            }
+        }
+
+        impl core::ops::DivAssign<$int> for Q15p8 {
+            fn div_assign(&mut self, other: $int) {
+                *self = *self / other;
+            }
+        }
+
+        impl core::ops::Div<Q15p8> for $int {
+            type Output = Q15p8;
+
+            fn div(self, other: Q15p8) -> Q15p8 {
+                Q15p8::from_q(saturating!(
+                    int_div24(self as i16, other.to_q()),
+                    int_div24(self as i16, other.to_q()).0
+                ))
+            }
+        }
+    };
+}
+
+impl_int_ops!(i8);
+impl_int_ops!(i16);
+
+macro_rules! impl_q7p8_ops {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl core::ops::$trait<crate::Q7p8> for Q15p8 {
+            type Output = Self;
+
+            fn $fn(self, other: crate::Q7p8) -> Self {
+                core::ops::$trait::$fn(self, other.to_q15p8())
+            }
+        }
+
+        impl core::ops::$trait<Q15p8> for crate::Q7p8 {
+            type Output = Q15p8;
+
+            fn $fn(self, other: Q15p8) -> Q15p8 {
+                core::ops::$trait::$fn(self.to_q15p8(), other)
+            }
+        }
+
+        impl core::ops::$assign_trait<crate::Q7p8> for Q15p8 {
+            fn $assign_fn(&mut self, other: crate::Q7p8) {
+                core::ops::$assign_trait::$assign_fn(self, other.to_q15p8());
+            }
+        }
+    };
+}
+
+impl_q7p8_ops!(Add, add, AddAssign, add_assign);
+impl_q7p8_ops!(Sub, sub, SubAssign, sub_assign);
+impl_q7p8_ops!(Mul, mul, MulAssign, mul_assign);
+impl_q7p8_ops!(Div, div, DivAssign, div_assign);
+
+impl core::ops::Div<crate::Reciprocal> for Q15p8 {
+    type Output = Self;
+
+    fn div(self, other: crate::Reciprocal) -> Self {
+        Self::div_reciprocal(self, other)
+    }
+}
+
+impl core::ops::DivAssign<crate::Reciprocal> for Q15p8 {
+    fn div_assign(&mut self, other: crate::Reciprocal) {
+        *self = *self / other;
     }
 }
 
@@ -0,0 +1,148 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Q, saturation::saturating};
+
+/// Q23.8 fixed point number.
+///
+/// This is a 32 bit number with 23 integer bits and 8 fractional bits.
+///
+/// All operations of the generic [Q] type are available.
+/// The multiplication and the division don't need 64 bit arithmetic.
+///
+/// ```
+/// use avr_q::{q23p8, Q23p8};
+///
+/// let a = q23p8!(const 100000);
+/// let b = q23p8!(const 3 / 2);
+/// assert_eq!(a * b, q23p8!(const 150000));
+/// assert_eq!(a / b, Q23p8::from_fraction(200000, 3));
+/// ```
+pub type Q23p8 = Q<i32, 8>;
+
+/// Construct a Q23.8 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the Q23.8 value and the fractional part is zero.
+///
+/// `q23p8!(42)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `q23p8!(42 / 10)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `q23p8!(const 42 / 10)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! q23p8 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::Q23p8::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::Q23p8::from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::Q23p8::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::Q23p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::Q23p8::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::Q23p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::Q23p8::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::Q23p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::Q23p8::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::Q23p8::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::Q23p8::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::Q23p8::from_int($numerator)
+    };
+}
+
+impl Q23p8 {
+    /// Convert this [Q23p8] to a [crate::Q7p8].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::const_overflowing_from_i32(self.to_q()).0
+    }
+
+    /// Convert this [Q23p8] to a [crate::Q15p8].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::const_overflowing_from_i32(self.to_q()).0
+    }
+
+    /// Convert this [Q23p8] to a [crate::Q15p16].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q15p16(&self) -> crate::Q15p16 {
+        crate::q15p16::q15p16_from_q8(self.to_q()).0
+    }
+}
+
+impl From<crate::Q7p8> for Q23p8 {
+    fn from(v: crate::Q7p8) -> Q23p8 {
+        v.to_q23p8()
+    }
+}
+
+impl From<crate::Q15p8> for Q23p8 {
+    fn from(v: crate::Q15p8) -> Q23p8 {
+        v.to_q23p8()
+    }
+}
+
+impl From<Q23p8> for crate::Q7p8 {
+    fn from(v: Q23p8) -> crate::Q7p8 {
+        saturating!(
+            crate::Q7p8::const_overflowing_from_i32(v.to_q()),
+            v.to_q7p8()
+        )
+    }
+}
+
+impl From<Q23p8> for crate::Q15p8 {
+    fn from(v: Q23p8) -> crate::Q15p8 {
+        saturating!(
+            crate::Q15p8::const_overflowing_from_i32(v.to_q()),
+            v.to_q15p8()
+        )
+    }
+}
+
+impl From<crate::Q15p16> for Q23p8 {
+    fn from(v: crate::Q15p16) -> Q23p8 {
+        v.to_q23p8()
+    }
+}
+
+impl From<Q23p8> for crate::Q15p16 {
+    fn from(v: Q23p8) -> crate::Q15p16 {
+        saturating!(crate::q15p16::q15p16_from_q8(v.to_q()), v.to_q15p16())
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,145 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Q, saturation::saturating};
+use avr_int24::I24;
+
+/// Q3.4 fixed point number.
+///
+/// This is an 8 bit number in the range [-8, 8) with a resolution of 1/16.
+/// It is intended for compact lookup tables and state arrays.
+/// Computations on larger values should be done after widening to
+/// [crate::Q7p8] or [crate::Q15p8], which is lossless.
+///
+/// All operations of the generic [Q] type are available.
+/// The arithmetic operations saturate.
+///
+/// ```
+/// use avr_q::{q3p4, q7p8, Q3p4, Q7p8};
+///
+/// const TABLE: [Q3p4; 3] = [q3p4!(const 1 / 4), q3p4!(const -3 / 2), q3p4!(const 7)];
+///
+/// assert_eq!(TABLE[0] + TABLE[1], q3p4!(const -5 / 4));
+/// assert_eq!(TABLE[2] + TABLE[2], Q3p4::from_q(i8::MAX));
+/// assert_eq!(Q7p8::from(TABLE[2]) * q7p8!(const 2), q7p8!(const 14));
+/// assert_eq!(Q3p4::from(q7p8!(const 14)), Q3p4::from_q(i8::MAX));
+/// ```
+pub type Q3p4 = Q<i8, 4>;
+
+/// Construct a Q3.4 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the Q3.4 value and the fractional part is zero.
+///
+/// `q3p4!(-3)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `q3p4!(3 / 4)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `q3p4!(const 3 / 4)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! q3p4 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::Q3p4::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::Q3p4::from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::Q3p4::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::Q3p4::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::Q3p4::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::Q3p4::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::Q3p4::from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::Q3p4::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::Q3p4::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::Q3p4::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::Q3p4::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::Q3p4::from_int($numerator)
+    };
+}
+
+/// Convert a raw Q.8 value to [Q3p4].
+/// The lower fractional bits are rounded towards negative infinity.
+/// The value saturates, if it doesn't fit.
+/// Returns the saturated value and `true`, if saturation happened.
+pub(crate) const fn q3p4_from_q8(q: i32) -> (Q3p4, bool) {
+    let q = q >> 4;
+    if q > i8::MAX as i32 {
+        (Q3p4::from_q(i8::MAX), true)
+    } else if q < i8::MIN as i32 {
+        (Q3p4::from_q(i8::MIN), true)
+    } else {
+        (Q3p4::from_q(q as i8), false)
+    }
+}
+
+impl Q3p4 {
+    /// Convert this [Q3p4] to a [crate::Q7p8].
+    /// The conversion is lossless.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::from_q((self.to_q() as i16) << 4)
+    }
+
+    /// Convert this [Q3p4] to a [crate::Q15p8].
+    /// The conversion is lossless.
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i16((self.to_q() as i16) << 4))
+    }
+}
+
+impl From<crate::Q7p8> for Q3p4 {
+    fn from(v: crate::Q7p8) -> Q3p4 {
+        saturating!(q3p4_from_q8(v.to_q() as i32), v.to_q3p4())
+    }
+}
+
+impl From<crate::Q15p8> for Q3p4 {
+    fn from(v: crate::Q15p8) -> Q3p4 {
+        saturating!(q3p4_from_q8(v.to_q().to_i32()), v.to_q3p4())
+    }
+}
+
+impl From<Q3p4> for crate::Q7p8 {
+    fn from(v: Q3p4) -> crate::Q7p8 {
+        v.to_q7p8()
+    }
+}
+
+impl From<Q3p4> for crate::Q15p8 {
+    fn from(v: Q3p4) -> crate::Q15p8 {
+        v.to_q15p8()
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
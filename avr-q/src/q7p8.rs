@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
+use crate::{Q, asm::asm_mulu8, round::Round, saturation::saturating};
 use avr_int24::I24;
 
 /// Q7.8 fixed point number.
+///
+/// This is a thin wrapper around `Q<i16, 8>` with additional Q7.8 specific operations.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[repr(transparent)]
-pub struct Q7p8(i16);
+pub struct Q7p8(Q<i16, 8>);
 
 /// Construct a Q7.8 fixed point number.
 ///
@@ -71,6 +74,204 @@ macro_rules! q7p8 {
     };
 }
 
+/// Mask of the fractional bits of a raw Q7.8 value.
+const FRAC_MASK: i16 = (1 << Q7p8::SHIFT) - 1;
+
+/// Raw 24 bit limits.
+const I24_MIN: I24 = I24::from_i32(-0x80_0000);
+const I24_MAX: I24 = I24::from_i32(0x7F_FFFF);
+
+/// Multiply two raw Q7.8 values.
+/// Returns the saturated product and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn mul_q7p8(a: i16, b: i16) -> (i16, bool) {
+    let c = I24::from_i16(a) * I24::from_i16(b);
+    // The Q7.8 result saturates exactly if the 24 bit product saturates.
+    // A product on the 24 bit limits can still be exact, though.
+    let sat = (c == I24_MIN || c == I24_MAX) && a as i32 * b as i32 != c.to_i32();
+    (c.shr8().to_i16(), sat)
+}
+
+/// Divide two raw Q7.8 values.
+/// Returns the saturated quotient and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn div_q7p8(a: i16, b: i16) -> (i16, bool) {
+    let c = if a == i16::MIN && b != -1 {
+        // The 24 bit division can't take the magnitude of the shifted minimum.
+        // Use the division with 32 bit intermediate instead.
+        I24::from_i16(a).shl8div(I24::from_i16(b))
+    } else {
+        // Division by zero and MIN / -1 saturate in the 24 bit division.
+        I24::from_i16(a).shl8() / I24::from_i16(b)
+    };
+    let q = c.to_i16();
+    (q, b == 0 || I24::from_i16(q) != c)
+}
+
+/// Multiply two raw Q7.8 values.
+/// The product is rounded according to `round`.
+/// Returns the saturated product and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn mul_q7p8_round(a: i16, b: i16, round: Round) -> (i16, bool) {
+    shr8_round(mul_q7p8_exact(a, b), round)
+}
+
+/// Multiply a raw Q7.8 value by an integer.
+/// Returns the saturated product and `true`, if saturation happened.
+///
+/// The magnitudes are split into 8 bit limbs,
+/// so that only 8x8 bit multiplications are needed.
+#[inline(always)]
+fn mul_int_q7p8(a: i16, int: i16) -> (i16, bool) {
+    let neg = (a < 0) != (int < 0);
+    let sat = (if neg { i16::MIN } else { i16::MAX }, true);
+    let [al, ah] = a.unsigned_abs().to_le_bytes();
+    let [bl, bh] = int.unsigned_abs().to_le_bytes();
+
+    // If both high limbs are non-zero, then the product has more than 16 bits.
+    // Otherwise only one of the middle limb products can be non-zero.
+    let mid = if ah == 0 {
+        asm_mulu8(al, bh)
+    } else if bh == 0 {
+        asm_mulu8(ah, bl)
+    } else {
+        return sat;
+    };
+    if mid > 0x80 {
+        return sat;
+    }
+    let Some(c) = (mid << 8).checked_add(asm_mulu8(al, bl)) else {
+        return sat;
+    };
+    if c <= i16::MAX as u16 {
+        (if neg { -(c as i16) } else { c as i16 }, false)
+    } else if neg && c == i16::MIN.unsigned_abs() {
+        (i16::MIN, false)
+    } else {
+        sat
+    }
+}
+
+/// Divide a raw Q7.8 value by an integer.
+/// Returns the saturated quotient and `true`, if saturation happened.
+#[inline(always)]
+fn div_int_q7p8(a: i16, int: i16) -> (i16, bool) {
+    match a.checked_div(int) {
+        Some(q) => (q, false),
+        // Division by zero or MIN / -1.
+        None => (
+            if a < 0 && int == 0 {
+                i16::MIN
+            } else {
+                i16::MAX
+            },
+            true,
+        ),
+    }
+}
+
+/// Divide an integer by a raw Q7.8 value.
+/// Returns the saturated quotient and `true`, if saturation happened.
+#[inline(always)]
+fn int_div_q7p8(int: i16, b: i16) -> (i16, bool) {
+    let neg = (int < 0) != (b < 0);
+    let n = int.unsigned_abs();
+    let d = b.unsigned_abs();
+    if n >= d {
+        // Division by zero or the quotient has more than 16 bits.
+        return (if neg { i16::MIN } else { i16::MAX }, true);
+    }
+    let c = shl16_div_u16(n, d);
+    if c > i16::MAX as u16 + neg as u16 {
+        (if neg { i16::MIN } else { i16::MAX }, true)
+    } else if neg {
+        (c.wrapping_neg() as i16, false)
+    } else {
+        (c as i16, false)
+    }
+}
+
+/// Divide `n` left shifted by 16 bits by `d`.
+/// `n` must be smaller than `d`, so that the quotient fits into 16 bits.
+#[inline(always)]
+fn shl16_div_u16(n: u16, d: u16) -> u16 {
+    let mut rem = n;
+    let mut q = 0;
+    for _ in 0..16_u8 {
+        // The remainder is smaller than the divisor of at most 2^15.
+        // Therefore, the shifted remainder can't overflow.
+        rem <<= 1;
+        q <<= 1;
+        if rem >= d {
+            rem -= d;
+            q |= 1;
+        }
+    }
+    q
+}
+
+/// Multiply two raw Q7.8 values and add a third raw Q7.8 value.
+/// The exact result is rounded once according to `round`.
+/// Returns the saturated result and `true`, if saturation happened.
+#[inline(always)]
+fn mul_add_q7p8(a: i16, b: i16, c: i16, round: Round) -> (i16, bool) {
+    // The magnitude of the product is at most 2^30
+    // and the magnitude of the shifted addend is at most 2^23.
+    // Therefore, the sum can't overflow.
+    shr8_round(mul_q7p8_exact(a, b) + ((c as i32) << 8), round)
+}
+
+/// Multiply two raw Q7.8 values.
+/// Returns the exact product with 16 fractional bits.
+#[inline(always)]
+fn mul_q7p8_exact(a: i16, b: i16) -> i32 {
+    let c = I24::from_i16(a) * I24::from_i16(b);
+    if c == I24_MIN || c == I24_MAX {
+        // The 24 bit product may be saturated.
+        a as i32 * b as i32
+    } else {
+        c.to_i32()
+    }
+}
+
+/// Right shift a value with 16 fractional bits to a raw Q7.8 value.
+/// The shifted out bits are rounded according to `round`.
+/// Returns the saturated value and `true`, if saturation happened.
+#[inline(always)]
+fn shr8_round(p: i32, round: Round) -> (i16, bool) {
+    let neg = p < 0;
+    let m = p.unsigned_abs();
+    let q = m >> 8;
+    let q = q + round.round_up(neg, q & 1 != 0, m & 0xFF, 0x100) as u32;
+    let q = if neg { -(q as i32) } else { q as i32 };
+    let (q, sat) = Q7p8::const_overflowing_from_i32(q);
+    (q.to_q(), sat)
+}
+
+/// Divide two raw Q7.8 values.
+/// The quotient is rounded according to `round`.
+/// Returns the saturated quotient and `true`, if saturation happened.
+#[inline(always)]
+pub(crate) fn div_q7p8_round(a: i16, b: i16, round: Round) -> (i16, bool) {
+    let (q, sat) = div_q7p8(a, b);
+    if sat {
+        // Rounding can't move the quotient back into range.
+        return (q, true);
+    }
+    // The remainder of the truncating division fits into 16 bits.
+    // Therefore, the lower 16 bits of the calculation are sufficient.
+    let rem = (a << 8).wrapping_sub(q.wrapping_mul(b));
+    let neg = (a < 0) != (b < 0);
+    let odd = q & 1 != 0;
+    if round.round_up(neg, odd, rem.unsigned_abs() as u32, b.unsigned_abs() as u32) {
+        let (q, sat) =
+            Q7p8::const_overflowing_from_i32(if neg { q as i32 - 1 } else { q as i32 + 1 });
+        (q.to_q(), sat)
+    } else {
+        (q, false)
+    }
+}
+
 #[allow(clippy::should_implement_trait)]
 impl Q7p8 {
     /// Length of the fractional part, in bits.
@@ -78,7 +279,7 @@ impl Q7p8 {
 
     /// Convert a raw Q7.8 value to [Q7p8].
     pub const fn from_q(q: i16) -> Self {
-        Self(q)
+        Self(Q::<i16, 8>::from_q(q))
     }
 
     /// Convert an integer value to [Q7p8] with fractional part being zero.
@@ -88,7 +289,7 @@ impl Q7p8 {
 
     /// Convert a numerator/denominator fraction to [Q7p8].
     pub fn from_fraction(numerator: i16, denominator: i16) -> Self {
-        Self(numerator) / Self(denominator)
+        Self::from_q(numerator) / Self::from_q(denominator)
     }
 
     /// Convert a numerator/denominator fraction to [Q7p8].
@@ -97,46 +298,134 @@ impl Q7p8 {
     /// Only call this function from const context.
     /// From non-const context use the optimized variant [Q7p8::from_fraction] instead.
     pub const fn const_from_fraction(numerator: i16, denominator: i16) -> Self {
-        Self(numerator).const_div(Self(denominator))
+        Self::from_q(numerator).const_div(Self::from_q(denominator))
     }
 
     /// Convert this [Q7p8] to a raw Q7.8 value.
     pub const fn to_q(self) -> i16 {
-        self.0
+        self.0.to_q()
     }
 
     /// Extract the integer part out of this [Q7p8].
+    /// The value is rounded towards negative infinity, like [Q7p8::floor].
     pub const fn to_int(self) -> i8 {
         (self.to_q() >> Self::SHIFT) as i8
     }
 
+    /// Round this [Q7p8] to the nearest integer, with ties rounded away from zero,
+    /// and convert it to an integer.
+    /// The result saturates.
+    pub const fn to_int_round(self) -> i8 {
+        self.round().to_int()
+    }
+
+    /// Round this [Q7p8] towards positive infinity and convert it to an integer.
+    /// The result saturates.
+    pub const fn to_int_ceil(self) -> i8 {
+        self.ceil().to_int()
+    }
+
+    /// Round this [Q7p8] towards zero and convert it to an integer.
+    pub const fn to_int_trunc(self) -> i8 {
+        self.trunc().to_int()
+    }
+
+    /// Round this [Q7p8] towards negative infinity to an integral value.
+    pub const fn floor(self) -> Self {
+        Self::from_q(self.to_q() & !FRAC_MASK)
+    }
+
+    /// Round this [Q7p8] towards positive infinity to an integral value.
+    /// The result saturates.
+    pub const fn ceil(self) -> Self {
+        Self::const_overflowing_from_i32(
+            (self.to_q() as i32 + FRAC_MASK as i32) & !(FRAC_MASK as i32),
+        )
+        .0
+    }
+
+    /// Round this [Q7p8] to the nearest integral value, with ties rounded away from zero.
+    /// The result saturates.
+    pub const fn round(self) -> Self {
+        let m =
+            (self.to_q().unsigned_abs() as i32 + (1 << (Self::SHIFT - 1))) & !(FRAC_MASK as i32);
+        Self::const_overflowing_from_i32(if self.to_q() < 0 { -m } else { m }).0
+    }
+
+    /// Round this [Q7p8] towards zero to an integral value.
+    pub const fn trunc(self) -> Self {
+        Self::from_q(self.to_q() - self.fract().to_q())
+    }
+
+    /// Get the fractional part of this [Q7p8].
+    /// This is `self - self.trunc()`, which has the same sign as `self`.
+    pub const fn fract(self) -> Self {
+        Self::from_q(self.to_q() % (1 << Self::SHIFT))
+    }
+
     /// Convert this [Q7p8] to a [crate::Q15p8].
     pub const fn to_q15p8(&self) -> crate::Q15p8 {
         crate::Q15p8::from_q(I24::from_i16(self.to_q()))
     }
 
+    /// Convert this [Q7p8] to a [crate::Q15p16].
+    pub const fn to_q15p16(&self) -> crate::Q15p16 {
+        crate::Q15p16::from_q((self.to_q() as i32) << 8)
+    }
+
+    /// Convert this [Q7p8] to a [crate::Q23p8].
+    pub const fn to_q23p8(&self) -> crate::Q23p8 {
+        crate::Q23p8::from_q(self.to_q() as i32)
+    }
+
+    /// Convert this [Q7p8] to a [crate::Q0p15].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q0p15(&self) -> crate::Q0p15 {
+        crate::q0p15::q0p15_from_q8(self.to_q() as i32).0
+    }
+
+    /// Convert this [Q7p8] to a [crate::Q3p4].
+    /// The lower fractional bits are rounded towards negative infinity.
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q3p4(&self) -> crate::Q3p4 {
+        crate::q3p4::q3p4_from_q8(self.to_q() as i32).0
+    }
+
+    /// Convert this [Q7p8] to a [crate::Q0p7].
+    /// The lower fractional bits are rounded towards negative infinity.
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q0p7(&self) -> crate::Q0p7 {
+        crate::q0p7::q0p7_from_q8(self.to_q() as i32).0
+    }
+
+    /// Convert this [Q7p8] to a [crate::UQ8p8].
+    /// Negative values saturate at zero.
+    pub const fn to_uq8p8(&self) -> crate::UQ8p8 {
+        crate::uq8p8::uq8p8_from_q8(self.to_q() as i32).0
+    }
+
+    /// Convert this [Q7p8] to a [crate::UQ16p8].
+    /// Negative values saturate at zero.
+    pub const fn to_uq16p8(&self) -> crate::UQ16p8 {
+        crate::uq16p8::uq16p8_from_q8(self.to_q() as i32).0
+    }
+
     /// Add and saturate two [Q7p8] values.
     #[inline(never)]
     pub const fn add(self, other: Self) -> Self {
-        Self(self.0.saturating_add(other.0))
+        Self(self.0.const_add(other.0))
     }
 
     /// Subtract and saturate two [Q7p8] values.
     #[inline(never)]
     pub const fn sub(self, other: Self) -> Self {
-        Self(self.0.saturating_sub(other.0))
+        Self(self.0.const_sub(other.0))
     }
 
     /// Multiply and saturate two [Q7p8] values.
-    #[inline(never)]
+    #[inline(always)]
     pub fn mul(self, other: Self) -> Self {
-        const {
-            assert!(Self::SHIFT == 8);
-        }
-        let a = I24::from_i16(self.0);
-        let b = I24::from_i16(other.0);
-        let c = (a * b).shr8();
-        Self(c.to_i16())
+        Self(self.0.mul(other.0))
     }
 
     /// Multiply and saturate two [Q7p8] values.
@@ -145,52 +434,805 @@ impl Q7p8 {
     /// Only call this function from const context.
     /// From non-const context use the optimized variant [Q7p8::mul] instead.
     pub const fn const_mul(self, other: Self) -> Self {
-        const {
-            assert!(Self::SHIFT == 8);
-        }
-        let a = I24::from_i16(self.0);
-        let b = I24::from_i16(other.0);
-        let c = (a.const_mul(b)).shr8();
-        Self(c.to_i16())
+        Self(self.0.const_mul(other.0))
     }
 
     /// Divide and saturate two [Q7p8] values.
-    #[inline(never)]
+    /// Division by zero saturates, too.
+    #[inline(always)]
     pub fn div(self, other: Self) -> Self {
-        const {
-            assert!(Self::SHIFT == 8);
-        }
-        let a = I24::from_i16(self.0);
-        let b = I24::from_i16(other.0);
-        let c = a.shl8() / b;
-        Self(c.to_i16())
+        Self(self.0.div(other.0))
     }
 
     /// Divide and saturate two [Q7p8] values.
+    /// Division by zero saturates, too.
     /// Const variant.
     ///
     /// Only call this function from const context.
     /// From non-const context use the optimized variant [Q7p8::div] instead.
     pub const fn const_div(self, other: Self) -> Self {
-        const {
-            assert!(Self::SHIFT == 8);
+        Self(self.0.const_div(other.0))
+    }
+
+    /// Multiply and saturate two [Q7p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    #[inline(always)]
+    pub fn mul_round(self, other: Self) -> Self {
+        Self(self.0.mul_round(other.0))
+    }
+
+    /// Multiply and saturate two [Q7p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::mul_round] instead.
+    pub const fn const_mul_round(self, other: Self) -> Self {
+        Self(self.0.const_mul_round(other.0))
+    }
+
+    /// Multiply and saturate two [Q7p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    #[inline(always)]
+    pub fn mul_round_even(self, other: Self) -> Self {
+        Self(self.0.mul_round_even(other.0))
+    }
+
+    /// Multiply and saturate two [Q7p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::mul_round_even] instead.
+    pub const fn const_mul_round_even(self, other: Self) -> Self {
+        Self(self.0.const_mul_round_even(other.0))
+    }
+
+    /// Divide and saturate two [Q7p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    #[inline(always)]
+    pub fn div_round(self, other: Self) -> Self {
+        Self(self.0.div_round(other.0))
+    }
+
+    /// Divide and saturate two [Q7p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::div_round] instead.
+    pub const fn const_div_round(self, other: Self) -> Self {
+        Self(self.0.const_div_round(other.0))
+    }
+
+    /// Divide and saturate two [Q7p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    #[inline(always)]
+    pub fn div_round_even(self, other: Self) -> Self {
+        Self(self.0.div_round_even(other.0))
+    }
+
+    /// Divide and saturate two [Q7p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::div_round_even] instead.
+    pub const fn const_div_round_even(self, other: Self) -> Self {
+        Self(self.0.const_div_round_even(other.0))
+    }
+
+    /// Multiply two [Q7p8] values and return the product as [crate::Q15p8].
+    /// The product always fits into the wider format and never saturates.
+    /// The lower fractional bits are rounded towards negative infinity.
+    #[inline(never)]
+    pub fn widening_mul(self, other: Self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i32(
+            mul_q7p8_exact(self.to_q(), other.to_q()) >> 8,
+        ))
+    }
+
+    /// Multiply two [Q7p8] values and return the product as [crate::Q15p8].
+    /// The product always fits into the wider format and never saturates.
+    /// The lower fractional bits are rounded towards negative infinity.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::widening_mul] instead.
+    pub const fn const_widening_mul(self, other: Self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i32(
+            (self.to_q() as i32 * other.to_q() as i32) >> 8,
+        ))
+    }
+
+    /// Multiply two [Q7p8] values and return the product as [crate::Q15p16].
+    /// The product keeps all 16 fractional bits and is always exact.
+    #[inline(never)]
+    pub fn widening_mul_exact(self, other: Self) -> crate::Q15p16 {
+        crate::Q15p16::from_q(mul_q7p8_exact(self.to_q(), other.to_q()))
+    }
+
+    /// Multiply two [Q7p8] values and return the product as [crate::Q15p16].
+    /// The product keeps all 16 fractional bits and is always exact.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::widening_mul_exact] instead.
+    pub const fn const_widening_mul_exact(self, other: Self) -> crate::Q15p16 {
+        crate::Q15p16::from_q(self.to_q() as i32 * other.to_q() as i32)
+    }
+
+    /// Calculate the reciprocal `1 / self` and saturate.
+    ///
+    /// The result is exact and identical to `q7p8!(1) / self`,
+    /// which is rounded towards zero.
+    /// It is calculated with a table seed and Newton-Raphson refinement,
+    /// which is slightly faster than the full division on AVR.
+    /// Division by zero and tiny values saturate.
+    ///
+    /// To divide several values by the same divisor,
+    /// use a [crate::Reciprocal] of the divisor.
+    #[inline(never)]
+    pub fn recip(self) -> Self {
+        let q = crate::recip::recip_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(q),
+            Self::const_overflowing_from_i32(q).0
+        )
+    }
+
+    /// Divide this [Q7p8] value by a precomputed [crate::Reciprocal] and saturate.
+    /// See [crate::Reciprocal] for the error bound.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_reciprocal(self, divisor: crate::Reciprocal) -> Self {
+        let q = divisor.div_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(q),
+            Self::const_overflowing_from_i32(q).0
+        )
+    }
+
+    /// Multiply this [Q7p8] value by an integer and saturate.
+    /// The product is exact, unless it saturates.
+    #[inline(never)]
+    pub fn mul_int(self, int: i16) -> Self {
+        Self::from_q(saturating!(
+            mul_int_q7p8(self.to_q(), int),
+            mul_int_q7p8(self.to_q(), int).0
+        ))
+    }
+
+    /// Divide this [Q7p8] value by an integer and saturate.
+    /// The quotient is rounded towards zero.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_int(self, int: i16) -> Self {
+        Self::from_q(saturating!(
+            div_int_q7p8(self.to_q(), int),
+            div_int_q7p8(self.to_q(), int).0
+        ))
+    }
+
+    /// Multiply two [Q7p8] values and add a third [Q7p8] value.
+    /// This calculates `self * factor + addend` with only one rounding step.
+    /// The exact result is rounded to nearest, with ties rounded away from zero,
+    /// and saturated afterwards.
+    #[inline(never)]
+    pub fn mul_add(self, factor: Self, addend: Self) -> Self {
+        Self::from_q(saturating!(
+            mul_add_q7p8(self.to_q(), factor.to_q(), addend.to_q(), Round::Nearest),
+            mul_add_q7p8(self.to_q(), factor.to_q(), addend.to_q(), Round::Nearest).0
+        ))
+    }
+
+    /// Multiply two [Q7p8] values and add a third [Q7p8] value.
+    /// This calculates `self * factor + addend` with only one rounding step.
+    /// The exact result is rounded to nearest, with ties rounded away from zero,
+    /// and saturated afterwards.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::mul_add] instead.
+    pub const fn const_mul_add(self, factor: Self, addend: Self) -> Self {
+        let p = self.to_q() as i64 * factor.to_q() as i64 + ((addend.to_q() as i64) << Self::SHIFT);
+        let c = Round::Nearest.const_div(p, 1 << Self::SHIFT);
+        Self::const_overflowing_from_i32(c as i32).0
+    }
+
+    /// Calculate the square root of this [Q7p8] value.
+    /// The square root is exact and rounded towards zero.
+    /// The square root of a negative value saturates to zero.
+    #[inline(never)]
+    pub fn sqrt(self) -> Self {
+        let (r, neg) = crate::sqrt::sqrt_q8(self.to_q() as i32, 12);
+        Self::from_q(saturating!((r as i16, neg), r as i16))
+    }
+
+    /// Calculate the square root of this [Q7p8] value.
+    /// The square root is exact and rounded towards zero.
+    /// The square root of a negative value saturates to zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::sqrt] instead.
+    pub const fn const_sqrt(self) -> Self {
+        Self::from_q(crate::sqrt::sqrt_q8(self.to_q() as i32, 12).0 as i16)
+    }
+
+    /// Calculate the square root of this [Q7p8] value.
+    /// The square root is exact and rounded towards zero.
+    /// Returns `None`, if the value is negative.
+    pub const fn checked_sqrt(self) -> Option<Self> {
+        match crate::sqrt::sqrt_q8(self.to_q() as i32, 12) {
+            (r, false) => Some(Self::from_q(r as i16)),
+            (_, true) => None,
+        }
+    }
+
+    /// Calculate the sine of this [Q7p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact sine.
+    #[inline(never)]
+    pub fn sin(self) -> Self {
+        Self::from_q(crate::trig::sin_q8(self.to_q() as i32))
+    }
+
+    /// Calculate the sine of this [Q7p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact sine.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::sin] instead.
+    pub const fn const_sin(self) -> Self {
+        Self::from_q(crate::trig::sin_q8(self.to_q() as i32))
+    }
+
+    /// Calculate the cosine of this [Q7p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact cosine.
+    #[inline(never)]
+    pub fn cos(self) -> Self {
+        Self::from_q(crate::trig::cos_q8(self.to_q() as i32))
+    }
+
+    /// Calculate the cosine of this [Q7p8] angle in radians.
+    /// The result is within 0.6 LSB of the exact cosine.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::cos] instead.
+    pub const fn const_cos(self) -> Self {
+        Self::from_q(crate::trig::cos_q8(self.to_q() as i32))
+    }
+
+    /// Calculate the four-quadrant arctangent of `self` (y) and `other` (x) in radians.
+    /// The result is in the range `[-pi, pi]` and within 0.6 LSB of the exact angle.
+    /// The arctangent of two zeros is zero.
+    #[inline(never)]
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_q(
+            crate::cordic::atan2_i24(I24::from_i16(self.to_q()), I24::from_i16(other.to_q()))
+                .to_i16(),
+        )
+    }
+
+    /// Calculate the four-quadrant arctangent of `self` (y) and `other` (x) in radians.
+    /// The result is in the range `[-pi, pi]` and within 0.6 LSB of the exact angle.
+    /// The arctangent of two zeros is zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::atan2] instead.
+    pub const fn const_atan2(self, other: Self) -> Self {
+        Self::from_q(
+            crate::cordic::const_atan2_i24(I24::from_i16(self.to_q()), I24::from_i16(other.to_q()))
+                .to_i16(),
+        )
+    }
+
+    /// Calculate the arctangent of this [Q7p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    #[inline(never)]
+    pub fn atan(self) -> Self {
+        Self::from_q(
+            crate::cordic::atan2_i24(I24::from_i16(self.to_q()), I24::from_i32(1 << 8)).to_i16(),
+        )
+    }
+
+    /// Calculate the arctangent of this [Q7p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::atan] instead.
+    pub const fn const_atan(self) -> Self {
+        Self::from_q(
+            crate::cordic::const_atan2_i24(I24::from_i16(self.to_q()), I24::from_i32(1 << 8))
+                .to_i16(),
+        )
+    }
+
+    /// Calculate the arcsine of this [Q7p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    #[inline(never)]
+    pub fn asin(self) -> Self {
+        let (a, clamped) = crate::cordic::asin_i24(I24::from_i16(self.to_q()));
+        let a = Self::from_q(a.to_i16());
+        saturating!((a, clamped), a)
+    }
+
+    /// Calculate the arcsine of this [Q7p8] value in radians.
+    /// The result is in the range `[-pi/2, pi/2]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::asin] instead.
+    pub const fn const_asin(self) -> Self {
+        Self::from_q(crate::cordic::const_asin_i24(I24::from_i16(self.to_q())).to_i16())
+    }
+
+    /// Calculate the arccosine of this [Q7p8] value in radians.
+    /// The result is in the range `[0, pi]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    #[inline(never)]
+    pub fn acos(self) -> Self {
+        let (a, clamped) = crate::cordic::acos_i24(I24::from_i16(self.to_q()));
+        let a = Self::from_q(a.to_i16());
+        saturating!((a, clamped), a)
+    }
+
+    /// Calculate the arccosine of this [Q7p8] value in radians.
+    /// The result is in the range `[0, pi]` and within 0.6 LSB of the exact angle.
+    /// Values outside of the range `[-1, 1]` saturate to `-1` or `1`.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::acos] instead.
+    pub const fn const_acos(self) -> Self {
+        Self::from_q(crate::cordic::const_acos_i24(I24::from_i16(self.to_q())).to_i16())
+    }
+
+    /// Calculate the exponential function `e^self` of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    #[inline(never)]
+    pub fn exp(self) -> Self {
+        let r = crate::exp::exp_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(r),
+            Self::const_overflowing_from_i32(r).0
+        )
+    }
+
+    /// Calculate the exponential function `e^self` of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::exp] instead.
+    pub const fn const_exp(self) -> Self {
+        Self::const_overflowing_from_i32(crate::exp::exp_q8(self.to_q() as i32)).0
+    }
+
+    /// Calculate the power of two `2^self` of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    #[inline(never)]
+    pub fn exp2(self) -> Self {
+        let r = crate::exp::exp2_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(r),
+            Self::const_overflowing_from_i32(r).0
+        )
+    }
+
+    /// Calculate the power of two `2^self` of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact power and saturates.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::exp2] instead.
+    pub const fn const_exp2(self) -> Self {
+        Self::const_overflowing_from_i32(crate::exp::exp2_q8(self.to_q() as i32)).0
+    }
+
+    /// Calculate the natural logarithm of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    #[inline(never)]
+    pub fn ln(self) -> Self {
+        let r = crate::exp::ln_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(r),
+            Self::const_overflowing_from_i32(r).0
+        )
+    }
+
+    /// Calculate the natural logarithm of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::ln] instead.
+    pub const fn const_ln(self) -> Self {
+        Self::const_overflowing_from_i32(crate::exp::ln_q8(self.to_q() as i32)).0
+    }
+
+    /// Calculate the base 2 logarithm of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    #[inline(never)]
+    pub fn log2(self) -> Self {
+        let r = crate::exp::log2_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(r),
+            Self::const_overflowing_from_i32(r).0
+        )
+    }
+
+    /// Calculate the base 2 logarithm of this [Q7p8] value.
+    /// The result is within 0.6 LSB of the exact logarithm.
+    /// The logarithm of zero or of a negative value saturates to the minimum value.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::log2] instead.
+    pub const fn const_log2(self) -> Self {
+        Self::const_overflowing_from_i32(crate::exp::log2_q8(self.to_q() as i32)).0
+    }
+
+    /// Raise this [Q7p8] value to the power of `exponent` and saturate.
+    /// The error is less than 0.5 LSB plus a relative error of
+    /// `2^-23 * max(1, |exponent|)`.
+    ///
+    /// A zero base results in zero for positive exponents, in one for a zero exponent
+    /// and saturates for negative exponents.
+    /// A negative base saturates to zero.
+    #[inline(never)]
+    pub fn pow(self, exponent: Self) -> Self {
+        let (r, neg) = crate::exp::pow_q8(self.to_q() as i32, exponent.to_q() as i32);
+        let (p, sat) = Self::const_overflowing_from_i32(r);
+        saturating!((p, sat || neg), p)
+    }
+
+    /// Raise this [Q7p8] value to the power of `exponent` and saturate.
+    /// The error is less than 0.5 LSB plus a relative error of
+    /// `2^-23 * max(1, |exponent|)`.
+    ///
+    /// A zero base results in zero for positive exponents, in one for a zero exponent
+    /// and saturates for negative exponents.
+    /// A negative base saturates to zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::pow] instead.
+    pub const fn const_pow(self, exponent: Self) -> Self {
+        Self::const_overflowing_from_i32(
+            crate::exp::pow_q8(self.to_q() as i32, exponent.to_q() as i32).0,
+        )
+        .0
+    }
+
+    /// Raise this [Q7p8] value to the integer power `n` and saturate.
+    /// The power is calculated by exponentiation by squaring with a wide intermediate
+    /// and it is rounded to nearest, with ties rounded away from zero.
+    /// The result is exact for `n <= 8`.
+    /// For larger `n` the result is within 0.5 LSB plus a relative error of `2^-54`.
+    /// Zero to the power of zero is one.
+    #[inline(never)]
+    pub fn powi(self, n: u8) -> Self {
+        match n {
+            2 => return self.square(),
+            3 => return self.cube(),
+            _ => (),
         }
-        let a = I24::from_i16(self.0);
-        let b = I24::from_i16(other.0);
-        let c = a.shl8().const_div(b);
-        Self(c.to_i16())
+        let r = crate::exp::powi_q8(self.to_q() as i32, n);
+        saturating!(
+            Self::const_overflowing_from_i32(r),
+            Self::const_overflowing_from_i32(r).0
+        )
+    }
+
+    /// Raise this [Q7p8] value to the integer power `n` and saturate.
+    /// The power is calculated by exponentiation by squaring with a wide intermediate
+    /// and it is rounded to nearest, with ties rounded away from zero.
+    /// The result is exact for `n <= 8`.
+    /// For larger `n` the result is within 0.5 LSB plus a relative error of `2^-54`.
+    /// Zero to the power of zero is one.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::powi] instead.
+    pub const fn const_powi(self, n: u8) -> Self {
+        Self::const_overflowing_from_i32(crate::exp::const_powi_q8(self.to_q() as i32, n)).0
+    }
+
+    /// Square this [Q7p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    #[inline(never)]
+    pub fn square(self) -> Self {
+        self.mul_round(self)
+    }
+
+    /// Square this [Q7p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::square] instead.
+    pub const fn const_square(self) -> Self {
+        self.const_powi(2)
+    }
+
+    /// Cube this [Q7p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    #[inline(never)]
+    pub fn cube(self) -> Self {
+        let r = crate::exp::cube_q8(self.to_q() as i32);
+        saturating!(
+            Self::const_overflowing_from_i32(r),
+            Self::const_overflowing_from_i32(r).0
+        )
+    }
+
+    /// Cube this [Q7p8] value and saturate.
+    /// The result is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::cube] instead.
+    pub const fn const_cube(self) -> Self {
+        self.const_powi(3)
     }
 
     /// Negate and saturate this [Q7p8] value.
     #[inline(never)]
     pub const fn neg(self) -> Self {
-        Self(self.0.saturating_neg())
+        Self(self.0.const_neg())
     }
 
     /// Get the absolute and saturated value of this [Q7p8].
     #[inline(never)]
     pub const fn abs(self) -> Self {
-        Self(self.0.saturating_abs())
+        Self(self.0.const_abs())
+    }
+
+    /// Get the minimum of two [Q7p8] values.
+    pub const fn min(self, other: Self) -> Self {
+        if self.to_q() <= other.to_q() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Get the maximum of two [Q7p8] values.
+    pub const fn max(self, other: Self) -> Self {
+        if self.to_q() >= other.to_q() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict this [Q7p8] value to the range `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `min` is bigger than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.to_q() <= max.to_q(), "min > max");
+        self.max(min).min(max)
+    }
+
+    /// Get the sign of this [Q7p8] value.
+    /// Returns `-1`, `0` or `1`.
+    pub const fn signum(self) -> Self {
+        Self::from_q(((self.to_q() > 0) as i16 - (self.to_q() < 0) as i16) << Self::SHIFT)
+    }
+
+    /// Check whether this [Q7p8] value is smaller than zero.
+    pub const fn is_negative(self) -> bool {
+        self.to_q() < 0
+    }
+
+    /// Check whether this [Q7p8] value is bigger than zero.
+    pub const fn is_positive(self) -> bool {
+        self.to_q() > 0
+    }
+
+    /// Check whether this [Q7p8] value is zero.
+    pub const fn is_zero(self) -> bool {
+        self.to_q() == 0
+    }
+
+    /// Get the magnitude of this [Q7p8] value with the sign of `sign`.
+    /// Zero is treated as positive.
+    /// The magnitude of the minimum value saturates, if `sign` is not negative.
+    pub const fn copysign(self, sign: Self) -> Self {
+        // m is all ones, if the signs differ.
+        // (q ^ m) - m then is the negation of q.
+        let m = (self.to_q() ^ sign.to_q()) >> 15;
+        Self::from_q((self.to_q() ^ m).saturating_sub(m))
+    }
+
+    /// Get the absolute difference of two [Q7p8] values.
+    /// The result is exact.
+    pub const fn abs_diff(self, other: Self) -> crate::UQ8p8 {
+        crate::UQ8p8::from_q(self.to_q().abs_diff(other.to_q()))
+    }
+
+    /// Add two [Q7p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+
+    /// Subtract two [Q7p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+
+    /// Multiply two [Q7p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        Self(self.0.wrapping_mul(other.0))
+    }
+
+    /// Divide two [Q7p8] values.
+    /// The quotient is truncated towards zero and wraps around on overflow.
+    /// Division by zero saturates.
+    pub const fn wrapping_div(self, other: Self) -> Self {
+        Self(self.0.wrapping_div(other.0))
+    }
+
+    /// Negate this [Q7p8] value.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Add two [Q7p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Subtract two [Q7p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Multiply two [Q7p8] values.
+    /// Returns `None` on overflow.
+    #[inline(always)]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.0.checked_mul(other.0).map(Self)
+    }
+
+    /// Multiply two [Q7p8] values.
+    /// Returns `None` on overflow.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::checked_mul] instead.
+    pub const fn const_checked_mul(self, other: Self) -> Option<Self> {
+        match self.0.const_checked_mul(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Divide two [Q7p8] values.
+    /// Returns `None` on overflow or division by zero.
+    #[inline(always)]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.0.checked_div(other.0).map(Self)
+    }
+
+    /// Divide two [Q7p8] values.
+    /// Returns `None` on overflow or division by zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::checked_div] instead.
+    pub const fn const_checked_div(self, other: Self) -> Option<Self> {
+        match self.0.const_checked_div(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Negate this [Q7p8] value.
+    /// Returns `None` on overflow.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.0.checked_neg() {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Get the absolute value of this [Q7p8].
+    /// Returns `None` on overflow.
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.0.checked_abs() {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Add and saturate two [Q7p8] values.
+    /// Returns the saturated sum and `true`, if saturation happened.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_add(other.0);
+        (Self(c), sat)
+    }
+
+    /// Subtract and saturate two [Q7p8] values.
+    /// Returns the saturated difference and `true`, if saturation happened.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_sub(other.0);
+        (Self(c), sat)
+    }
+
+    /// Multiply and saturate two [Q7p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    #[inline(always)]
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_mul(other.0);
+        (Self(c), sat)
+    }
+
+    /// Multiply and saturate two [Q7p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::overflowing_mul] instead.
+    pub const fn const_overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.const_overflowing_mul(other.0);
+        (Self(c), sat)
+    }
+
+    /// Divide and saturate two [Q7p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    #[inline(always)]
+    pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.overflowing_div(other.0);
+        (Self(c), sat)
+    }
+
+    /// Divide and saturate two [Q7p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [Q7p8::overflowing_div] instead.
+    pub const fn const_overflowing_div(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.0.const_overflowing_div(other.0);
+        (Self(c), sat)
+    }
+
+    /// Convert and saturate a wide raw value to [Q7p8].
+    /// Returns the saturated value and `true`, if saturation happened.
+    pub(crate) const fn const_overflowing_from_i32(v: i32) -> (Self, bool) {
+        if v < i16::MIN as i32 {
+            (Self::from_q(i16::MIN), true)
+        } else if v > i16::MAX as i32 {
+            (Self::from_q(i16::MAX), true)
+        } else {
+            (Self::from_q(v as i16), false)
+        }
     }
 }
 
@@ -208,7 +1250,28 @@ impl From<Q7p8> for i8 {
 
 impl From<crate::Q15p8> for Q7p8 {
     fn from(v: crate::Q15p8) -> Q7p8 {
-        v.to_q7p8()
+        saturating!(
+            Self::const_overflowing_from_i32(v.to_q().to_i32()),
+            v.to_q7p8()
+        )
+    }
+}
+
+impl From<crate::UQ8p8> for Q7p8 {
+    fn from(v: crate::UQ8p8) -> Q7p8 {
+        saturating!(
+            Self::const_overflowing_from_i32(v.to_q() as i32),
+            v.to_q7p8()
+        )
+    }
+}
+
+impl From<crate::UQ16p8> for Q7p8 {
+    fn from(v: crate::UQ16p8) -> Q7p8 {
+        saturating!(
+            Self::const_overflowing_from_i32(v.to_q() as i32),
+            v.to_q7p8()
+        )
     }
 }
 
@@ -216,13 +1279,13 @@ impl core::ops::Add for Q7p8 {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        Self::add(self, other)
+        Self(self.0.add(other.0))
     }
 }
 
 impl core::ops::AddAssign for Q7p8 {
     fn add_assign(&mut self, other: Self) {
-        self.0 = (*self + other).0;
+        *self = *self + other;
     }
 }
 
@@ -230,13 +1293,13 @@ impl core::ops::Sub for Q7p8 {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        Self::sub(self, other)
+        Self(self.0.sub(other.0))
     }
 }
 
 impl core::ops::SubAssign for Q7p8 {
     fn sub_assign(&mut self, other: Self) {
-        self.0 = (*self - other).0;
+        *self = *self - other;
     }
 }
 
@@ -250,7 +1313,7 @@ impl core::ops::Mul for Q7p8 {
 
 impl core::ops::MulAssign for Q7p8 {
     fn mul_assign(&mut self, other: Self) {
-        self.0 = (*self * other).0;
+        *self = *self * other;
     }
 }
 
@@ -264,7 +1327,75 @@ impl core::ops::Div for Q7p8 {
 
 impl core::ops::DivAssign for Q7p8 {
     fn div_assign(&mut self, other: Self) {
-        self.0 = (*self / other).0;
+        *self = *self / other;
+    }
+}
+
+macro_rules! impl_int_ops {
+    ($int:ty) => {
+        impl core::ops::Mul<$int> for Q7p8 {
+            type Output = Self;
+
+            fn mul(self, other: $int) -> Self {
+                Self::mul_int(self, other as i16)
+            }
+        }
+
+        impl core::ops::MulAssign<$int> for Q7p8 {
+            fn mul_assign(&mut self, other: $int) {
+                *self = *self * other;
+            }
+        }
+
+        impl core::ops::Mul<Q7p8> for $int {
+            type Output = Q7p8;
+
+            fn mul(self, other: Q7p8) -> Q7p8 {
+                Q7p8::mul_int(other, self as i16)
+            }
+        }
+
+        impl core::ops::Div<$int> for Q7p8 {
+            type Output = Self;
+
+            fn div(self, other: $int) -> Self {
+                Self::div_int(self, other as i16)
+            }
+        }
+
+        impl core::ops::DivAssign<$int> for Q7p8 {
+            fn div_assign(&mut self, other: $int) {
+                *self = *self / other;
+            }
+        }
+
+        impl core::ops::Div<Q7p8> for $int {
+            type Output = Q7p8;
+
+            fn div(self, other: Q7p8) -> Q7p8 {
+                Q7p8::from_q(saturating!(
+                    int_div_q7p8(self as i16, other.to_q()),
+                    int_div_q7p8(self as i16, other.to_q()).0
+                ))
+            }
+        }
+    };
+}
+
+impl_int_ops!(i8);
+impl_int_ops!(i16);
+
+impl core::ops::Div<crate::Reciprocal> for Q7p8 {
+    type Output = Self;
+
+    fn div(self, other: crate::Reciprocal) -> Self {
+        Self::div_reciprocal(self, other)
+    }
+}
+
+impl core::ops::DivAssign<crate::Reciprocal> for Q7p8 {
+    fn div_assign(&mut self, other: crate::Reciprocal) {
+        *self = *self / other;
     }
 }
 
@@ -272,7 +1403,7 @@ impl core::ops::Neg for Q7p8 {
     type Output = Self;
 
     fn neg(self) -> Self {
-        Self::neg(self)
+        Self(self.0.neg())
     }
 }
 
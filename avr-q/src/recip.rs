@@ -0,0 +1,188 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Q7p8, Q15p8};
+
+/// Reciprocal seeds for the normalized divisors.
+///
+/// Entry `i` is `2^31` divided by the center of the interval
+/// `[2^15 + i * 2^11, 2^15 + (i + 1) * 2^11)`, rounded to nearest.
+const SEED: [u16; 16] = [
+    63550, 59919, 56680, 53773, 51150, 48771, 46603, 44620, //
+    42799, 41121, 39569, 38130, 36792, 35545, 34380, 33288,
+];
+
+/// Calculate `2^31 / xn`, rounded towards zero.
+/// `xn` must be normalized to the range `[2^15, 2^16)`.
+///
+/// The table seed has a relative error of less than 2^-5.
+/// Two Newton-Raphson steps refine it to within a few units
+/// and a final correction step makes it exact.
+/// All products fit into 32 bits.
+pub(crate) const fn recip_mant(xn: u16) -> u32 {
+    let d = xn as u32;
+    let mut y = SEED[((xn >> 11) & 0xF) as usize] as u32;
+    let mut i = 0;
+    while i < 2 {
+        // y = y * (2 - d * y)
+        // y never exceeds 2^16, so the products fit into 32 bits.
+        let p = (d * y) >> 16;
+        y = (y * (0x1_0000 - p)) >> 15;
+        i += 1;
+    }
+    // Due to the truncated products the refined y is up to 2 too large
+    // or up to 1 too small. The unit test checks this for all xn.
+    // Therefore, at most two correction steps down or one step up are needed.
+    let mut r = (1_u32 << 31).wrapping_sub(d * y) as i32;
+    if r < 0 {
+        y -= 1;
+        r += d as i32;
+    }
+    if r < 0 {
+        y -= 1;
+        r += d as i32;
+    }
+    if r >= d as i32 {
+        y += 1;
+    }
+    y
+}
+
+/// Calculate the reciprocal of a raw Q.8 value.
+/// The reciprocal is exact and rounded towards zero.
+///
+/// Returns the raw Q.8 reciprocal.
+/// The reciprocal of zero is 2^24.
+/// The caller has to saturate the reciprocal to its format.
+#[inline(always)]
+pub(crate) const fn recip_q8(q: i32) -> i32 {
+    let a = q.unsigned_abs();
+    let m = if a == 0 {
+        1 << 24
+    } else if a <= 1 << 16 {
+        // 2^16 / a = (2^31 / (a << s)) >> (15 - s)
+        let s = a.leading_zeros() as i32 - 16;
+        if s < 0 {
+            1
+        } else {
+            (recip_mant((a << s) as u16) >> (15 - s)) as i32
+        }
+    } else {
+        // The reciprocal is smaller than one LSB.
+        0
+    };
+    if q < 0 { -m } else { m }
+}
+
+/// Precomputed reciprocal of a [Q7p8] or [Q15p8] divisor.
+///
+/// Dividing by a [Reciprocal] multiplies the dividend by the precomputed reciprocal
+/// instead of doing a full division.
+/// This speeds up repeated divisions by the same divisor.
+///
+/// The quotient is rounded towards zero.
+/// Its magnitude is never smaller than the magnitude of the
+/// quotient of the full division.
+/// Divisors with up to 16 significant bits, which includes all [Q7p8] divisors,
+/// result in a quotient that is at most one LSB larger in magnitude,
+/// as long as the quotient is in the [Q7p8] range.
+/// Exact quotients in the [Q7p8] range are therefore always calculated exactly.
+/// In general the relative error of the quotient is less than 2^-14, plus one LSB.
+///
+/// Division by a zero [Reciprocal] saturates like the full division does.
+///
+/// ```
+/// use avr_q::{q7p8, Reciprocal};
+///
+/// let r = Reciprocal::from_q7p8(q7p8!(const 5));
+/// assert_eq!(q7p8!(const 10) / r, q7p8!(const 2));
+/// assert_eq!(q7p8!(const -1) / r, q7p8!(const -1 / 5));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Reciprocal {
+    /// `2^31 / xn` rounded up, where `xn` is the normalized magnitude of the divisor.
+    /// Zero, if the divisor is zero.
+    mant: u32,
+    /// Right shift of the product of the dividend and `mant`.
+    shift: u8,
+    /// The divisor is negative.
+    neg: bool,
+}
+
+impl Reciprocal {
+    /// Calculate the reciprocal of a raw Q.8 divisor.
+    const fn from_q8(q: i32) -> Self {
+        let a = q.unsigned_abs();
+        if a == 0 {
+            return Self {
+                mant: 0,
+                shift: 0,
+                neg: false,
+            };
+        }
+        // Normalize the magnitude to [2^15, 2^16).
+        // Divisors with more than 16 significant bits lose their lowest bits.
+        let lz = a.leading_zeros();
+        let (xn, shift) = if lz >= 16 {
+            (a << (lz - 16), 23 - (lz - 16))
+        } else {
+            (a >> (16 - lz), 23 + (16 - lz))
+        };
+        // Round the reciprocal up, so that exact quotients are never rounded down.
+        // 2^31 / xn is exact for the power of two only.
+        let mant = recip_mant(xn as u16) + (xn != 1 << 15) as u32;
+        Self {
+            mant,
+            shift: shift as u8,
+            neg: q < 0,
+        }
+    }
+
+    /// Calculate the reciprocal of a [Q7p8] divisor.
+    pub const fn from_q7p8(divisor: Q7p8) -> Self {
+        Self::from_q8(divisor.to_q() as i32)
+    }
+
+    /// Calculate the reciprocal of a [Q15p8] divisor.
+    pub const fn from_q15p8(divisor: Q15p8) -> Self {
+        Self::from_q8(divisor.to_q().to_i32())
+    }
+
+    /// Divide a raw Q.8 dividend by this reciprocal.
+    /// The magnitude of the dividend must not exceed 2^23.
+    ///
+    /// Returns the raw Q.8 quotient.
+    /// Quotients with a magnitude of more than 2^24 are clamped to 2^24.
+    /// The caller has to saturate the quotient to its format.
+    #[inline(always)]
+    pub(crate) const fn div_q8(&self, q: i32) -> i32 {
+        if self.mant == 0 {
+            // Division by zero saturates according to the sign of the dividend.
+            return if q < 0 { -(1 << 24) } else { 1 << 24 };
+        }
+        // The quotient is (a * mant) >> shift.
+        // The dividend is split into 8 bit limbs,
+        // so that the products fit into 32 bits.
+        let a = q.unsigned_abs();
+        let hi = (a >> 8) * self.mant;
+        let lo = ((a & 0xFF) * self.mant) >> 8;
+        let m = (hi + lo) >> (self.shift - 8);
+        let m = if m > 1 << 24 { 1 << 24 } else { m as i32 };
+        if (q < 0) != self.neg { -m } else { m }
+    }
+}
+
+impl From<Q7p8> for Reciprocal {
+    fn from(v: Q7p8) -> Reciprocal {
+        Reciprocal::from_q7p8(v)
+    }
+}
+
+impl From<Q15p8> for Reciprocal {
+    fn from(v: Q15p8) -> Reciprocal {
+        Reciprocal::from_q15p8(v)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
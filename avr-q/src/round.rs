@@ -0,0 +1,83 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+/// Rounding mode of an arithmetic operation.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Round {
+    /// Round towards zero.
+    Trunc,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round to nearest. Ties are rounded away from zero.
+    Nearest,
+    /// Round to nearest. Ties are rounded to even.
+    NearestEven,
+}
+
+impl Round {
+    /// Decide whether a truncated result magnitude has to be incremented by one.
+    ///
+    /// `neg` tells whether the exact result is negative.
+    /// `odd` tells whether the truncated magnitude is odd.
+    /// `rem` is the magnitude of the remainder and `div` is the magnitude of the divisor.
+    /// `rem` must be smaller than `div`.
+    #[inline(always)]
+    pub(crate) const fn round_up(self, neg: bool, odd: bool, rem: u32, div: u32) -> bool {
+        // rem > div - rem is equivalent to rem > div / 2,
+        // but it can't overflow and it doesn't lose the LSB of div.
+        match self {
+            Round::Trunc => false,
+            Round::Floor => neg && rem != 0,
+            Round::Nearest => rem >= div - rem,
+            Round::NearestEven => rem > div - rem || (rem == div - rem && odd),
+        }
+    }
+
+    /// Right shift `v` by `shift` bits and round the shifted out bits.
+    #[inline(always)]
+    pub(crate) const fn shr32(self, v: i32, shift: usize) -> i32 {
+        let neg = v < 0;
+        let m = v.unsigned_abs();
+        let q = m >> shift;
+        let rem = m & ((1 << shift) - 1);
+        let q = q + self.round_up(neg, q & 1 != 0, rem, 1 << shift) as u32;
+        if neg {
+            (q as i32).wrapping_neg()
+        } else {
+            q as i32
+        }
+    }
+
+    /// Divide `num` by `den` and round the quotient.
+    /// `den` must not be zero.
+    #[inline(always)]
+    pub(crate) const fn div32(self, num: i32, den: i32) -> i32 {
+        let neg = (num < 0) != (den < 0);
+        let n = num.unsigned_abs();
+        let d = den.unsigned_abs();
+        let q = n / d;
+        let q = q + self.round_up(neg, q & 1 != 0, n % d, d) as u32;
+        if neg {
+            (q as i32).wrapping_neg()
+        } else {
+            q as i32
+        }
+    }
+
+    /// Divide `num` by `den` and round the quotient.
+    /// `den` must not be zero and the magnitude of `den` must fit into 32 bits.
+    ///
+    /// This is intended for const context only.
+    pub(crate) const fn const_div(self, num: i64, den: i64) -> i64 {
+        let neg = (num < 0) != (den < 0);
+        let n = num.unsigned_abs();
+        let d = den.unsigned_abs();
+        let q = n / d;
+        let rem = n % d;
+        let q = q + self.round_up(neg, q & 1 != 0, rem as u32, d as u32) as u64;
+        if neg { -(q as i64) } else { q as i64 }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,103 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+#[cfg(feature = "saturation-flag")]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// The sticky saturation flag.
+///
+/// This is a single byte, which is only ever loaded from or stored to.
+/// Single byte loads and stores are atomic on AVR.
+#[cfg(feature = "saturation-flag")]
+static SATURATED: AtomicBool = AtomicBool::new(false);
+
+/// Sticky saturation status flag.
+///
+/// The flag is set by the saturating operations of all fixed-point types
+/// whenever the result has been clamped to the numeric limits.
+/// This includes the `From` conversions between the fixed-point types,
+/// such as `From<Q15p8> for Q7p8`.
+/// The flag is never reset by the arithmetic operations.
+/// It has to be cleared explicitly by the application,
+/// for example once per control cycle.
+///
+/// Only the non-const operations and the operator trait implementations
+/// can set the flag.
+/// The `const fn` operations, such as `Q7p8::add` or `Q15p8::const_mul`,
+/// can't access the flag and therefore never set it.
+/// Use the `+`, `-` and unary `-` operators instead of `Q7p8::add`, `Q7p8::sub`
+/// and `Q7p8::neg` to have saturations recorded in the flag.
+/// Likewise the `const fn` conversions, such as `Q15p8::to_q7p8`, never set the flag,
+/// but the corresponding `From` conversions do.
+/// The checked, wrapping and overflowing operations don't set the flag either,
+/// because they report overflows to the caller directly.
+///
+/// All operations on the flag are safe to be used from interrupt context.
+///
+/// This type is only available with the `saturation-flag` crate feature.
+#[cfg(feature = "saturation-flag")]
+pub struct SaturationFlag;
+
+#[cfg(feature = "saturation-flag")]
+impl SaturationFlag {
+    /// Get the state of the saturation flag.
+    #[inline(always)]
+    pub fn get() -> bool {
+        SATURATED.load(Ordering::Relaxed)
+    }
+
+    /// Clear the saturation flag.
+    #[inline(always)]
+    pub fn clear() {
+        SATURATED.store(false, Ordering::Relaxed);
+    }
+
+    /// Get the state of the saturation flag and clear it.
+    ///
+    /// A saturation that happens concurrently in interrupt context is never lost.
+    #[inline(never)]
+    pub fn take() -> bool {
+        // The flag is only cleared, if it has been observed to be set.
+        // A concurrent set between the load and the store is
+        // therefore already accounted for in the returned value.
+        // This avoids the need for an atomic swap, which AVR doesn't have.
+        if SATURATED.load(Ordering::Relaxed) {
+            SATURATED.store(false, Ordering::Relaxed);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Set the saturation flag, if `sat` is `true`.
+/// Returns the `value`.
+#[inline(always)]
+#[allow(unused_variables)]
+pub(crate) fn track<T>((value, sat): (T, bool)) -> T {
+    #[cfg(feature = "saturation-flag")]
+    if sat {
+        SATURATED.store(true, Ordering::Relaxed);
+    }
+    value
+}
+
+/// Evaluate a saturating operation.
+///
+/// If the `saturation-flag` feature is enabled, then the `$tracked` expression is evaluated.
+/// It evaluates to the result and the saturation status, which is stored in the flag.
+///
+/// Otherwise the `$plain` expression is evaluated, which evaluates to the result only.
+macro_rules! saturating {
+    ($tracked:expr, $plain:expr) => {
+        if cfg!(feature = "saturation-flag") {
+            $crate::saturation::track($tracked)
+        } else {
+            $plain
+        }
+    };
+}
+pub(crate) use saturating;
+
+// vim: ts=4 sw=4 expandtab
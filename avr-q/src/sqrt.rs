@@ -0,0 +1,44 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+/// Calculate the integer square root of `v`, rounded towards zero.
+/// The square root must be smaller than `2^bits`.
+///
+/// This is the bit-by-bit method, which determines one bit of the root per step
+/// and only uses additions, subtractions and shifts.
+/// The result is exact.
+#[inline(always)]
+pub(crate) const fn isqrt32(v: u32, bits: u32) -> u32 {
+    let mut rem = v;
+    let mut root = 0;
+    let mut bit = 1 << (2 * (bits - 1));
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+/// Calculate the square root of a raw Q.8 value.
+/// The square root is exact and rounded towards zero.
+/// The magnitude of `q` must be smaller than `2^(2 * bits - 8)`.
+///
+/// Returns the raw Q.8 square root and `false`.
+/// Returns zero and `true`, if `q` is negative.
+#[inline(never)]
+pub(crate) const fn sqrt_q8(q: i32, bits: u32) -> (i32, bool) {
+    if q < 0 {
+        (0, true)
+    } else {
+        // sqrt(q / 2^8) * 2^8 = sqrt(q * 2^8)
+        (isqrt32((q as u32) << 8, bits) as i32, false)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
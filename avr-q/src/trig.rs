@@ -0,0 +1,72 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+/// `2^32 / (2 * pi)`, split into the upper and the lower 16 bits.
+const RAD_TO_TURN_HI: u32 = 10430;
+const RAD_TO_TURN_LO: u32 = 24796;
+
+/// Coefficients of the sine polynomial with 15 fractional bits.
+///
+/// `sin(x * pi / 2) = x * (A1 - x^2 * (A3 - x^2 * A5))`
+/// is a minimax approximation for `x` in the range `[0, 1]`.
+/// The approximation error is less than 1.1 * 10^-4.
+const A1: u32 = 51469;
+const A3: u32 = 21083;
+const A5: u32 = 2385;
+
+/// Convert the magnitude of a raw Q.8 angle in radians to a phase in turns.
+/// One full turn of `2 * pi` corresponds to `2^16`.
+///
+/// The phase is rounded towards zero and wraps around.
+/// The calculation is split into 8 and 16 bit limbs,
+/// so that the products fit into 32 bits.
+#[inline(always)]
+const fn phase_q8(a: u32) -> u16 {
+    // The phase is bits 24 to 39 of a * 2^32 / (2 * pi).
+    // w holds bits 8 to 39 of the product.
+    let w = (a.wrapping_mul(RAD_TO_TURN_HI) << 8)
+        .wrapping_add((a >> 8).wrapping_mul(RAD_TO_TURN_LO))
+        .wrapping_add(((a & 0xFF) * RAD_TO_TURN_LO) >> 8);
+    (w >> 16) as u16
+}
+
+/// Calculate the sine of a phase in turns.
+/// One full turn corresponds to `2^16`.
+///
+/// Returns the raw Q.8 sine, rounded to nearest.
+#[inline(always)]
+const fn sin_phase(phase: u16) -> i16 {
+    // Reduce the phase to the first quadrant.
+    let quadrant = phase >> 14;
+    let x = (phase & 0x3FFF) as u32;
+    let x = if quadrant & 1 != 0 { 0x4000 - x } else { x };
+    // Evaluate the polynomial with 15 fractional bits.
+    let x = x << 1;
+    let x2 = (x * x) >> 15;
+    let y = A1 - ((x2 * (A3 - ((x2 * A5) >> 15))) >> 15);
+    let y = (x * y) >> 15;
+    let s = ((y + (1 << 6)) >> 7) as i16;
+    if quadrant & 2 != 0 { -s } else { s }
+}
+
+/// Calculate the sine of a raw Q.8 angle in radians.
+///
+/// Returns the raw Q.8 sine, rounded to nearest.
+/// The sine is calculated from the magnitude of the angle,
+/// so that it is exactly symmetric.
+#[inline(never)]
+pub(crate) const fn sin_q8(q: i32) -> i16 {
+    let s = sin_phase(phase_q8(q.unsigned_abs()));
+    if q < 0 { -s } else { s }
+}
+
+/// Calculate the cosine of a raw Q.8 angle in radians.
+///
+/// Returns the raw Q.8 cosine, rounded to nearest.
+#[inline(never)]
+pub(crate) const fn cos_q8(q: i32) -> i16 {
+    sin_phase(phase_q8(q.unsigned_abs()).wrapping_add(0x4000))
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -2,8 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
+#[cfg(feature = "curveipo")]
+mod curveipo;
+mod q;
+mod q0p15;
+mod q0p7;
+mod q15p16;
 mod q15p8;
+mod q23p8;
+mod q3p4;
 mod q7p8;
+mod recip;
+#[cfg(feature = "saturation-flag")]
+mod saturation;
+mod uq16p8;
+mod uq8p8;
 
 pub trait TestOps {
     fn print(&self, text: &str);
@@ -19,11 +32,160 @@ macro_rules! test_assert {
 }
 pub(crate) use test_assert;
 
-pub fn run_tests(t: &impl TestOps) {
-    t.print("\n\nBegin tests\n");
-    q7p8::test_q7p8(t);
-    q15p8::test_q15p8(t);
+/// Reference implementation of a division that rounds to nearest.
+/// Ties are rounded to even, if `even` is `true`.
+/// Otherwise ties are rounded away from zero.
+fn div_round_ref(num: i64, den: i64, even: bool) -> i64 {
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+    let q = num.div_euclid(den);
+    let r = num.rem_euclid(den);
+    if r * 2 > den || (r * 2 == den && (if even { q & 1 != 0 } else { num >= 0 })) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+/// 32 bit variant of [div_round_ref].
+/// The result saturates to the `i32` range.
+/// `den` must not be `i32::MIN`.
+fn div_round_ref32(num: i32, den: i32, even: bool) -> i32 {
+    if den < 0 {
+        // Both rounding modes are symmetric around zero.
+        return div_round_ref32(num, -den, even).saturating_neg();
+    }
+    let q = num.div_euclid(den);
+    let r = num.rem_euclid(den);
+    if r > den - r || (r == den - r && (if even { q & 1 != 0 } else { num >= 0 })) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+/// Exact reference of the raw Q.8 power `(q / 2^8)^n`,
+/// rounded to nearest with ties rounded away from zero.
+/// Returns `None`, if the calculation doesn't fit into 64 bits.
+fn powi_ref(q: i32, n: u8) -> Option<i64> {
+    if n == 0 {
+        return Some(1 << 8);
+    }
+    if q == 0 {
+        return Some(0);
+    }
+    // q^n / 2^(8 * (n - 1)) = odd^n * 2^(tz * n - 8 * (n - 1))
+    let tz = q.trailing_zeros() as i64;
+    let odd = (q.unsigned_abs() >> tz) as u64;
+    let mut m: u64 = 1;
+    for _ in 0..n {
+        m = m.checked_mul(odd)?;
+    }
+    let shift = tz * n as i64 - 8 * (n as i64 - 1);
+    let m = if shift >= 0 {
+        if shift >= 40 || m >= 1 << (40 - shift) {
+            return None;
+        }
+        (m << shift) as i64
+    } else if shift < -64 {
+        0
+    } else {
+        let h = (m >> (-shift - 1)) as i64;
+        (h >> 1) + (h & 1)
+    };
+    Some(if q < 0 && n & 1 != 0 { -m } else { m })
+}
+
+/// Number of test groups.
+///
+/// All tests together don't fit into the 32 kiB flash memory of the ATmega328P test runner.
+/// Therefore, the tests are split into groups that are built and run one at a time.
+/// The tests of each group have to fit into the flash memory.
+pub const NR_TEST_GROUPS: u8 = 15;
+
+/// Run all tests of the test group `GROUP`.
+pub fn run_test_group<const GROUP: u8>(t: &impl TestOps) {
+    const { assert!(GROUP < NR_TEST_GROUPS) };
+    t.print("\n\nBegin tests of group ");
+    t.print_num(GROUP.into());
+    t.print("\n");
+    match GROUP {
+        0 => {
+            q7p8::test_q7p8(t);
+            q0p15::test_q0p15(t);
+            q0p7::test_q0p7(t);
+            q3p4::test_q3p4(t);
+        }
+        1 => {
+            q15p8::test_q15p8(t);
+        }
+        2 => {
+            q15p8::test_q15p8_round(t);
+        }
+        3 => {
+            q7p8::test_q7p8_trig(t);
+            q15p8::test_q15p8_trig(t);
+        }
+        4 => {
+            q7p8::test_q7p8_exp(t);
+            q15p8::test_q15p8_exp(t);
+        }
+        5 => {
+            q15p16::test_q15p16(t);
+            q23p8::test_q23p8(t);
+            q::test_q(t);
+        }
+        6 => {
+            uq8p8::test_uq8p8(t);
+            uq16p8::test_uq16p8(t);
+        }
+        7 => {
+            recip::test_recip(t);
+            #[cfg(feature = "curveipo")]
+            curveipo::test_curveipo(t);
+        }
+        8 => {
+            #[cfg(feature = "saturation-flag")]
+            saturation::test_saturation(t);
+        }
+        9 => {
+            q::test_q_i8(t);
+        }
+        10 => {
+            q::test_q_i16(t);
+        }
+        11 => {
+            q::test_q_i16_fast(t);
+        }
+        12 => {
+            q::test_q_i24(t);
+        }
+        13 => {
+            q::test_q_i24_fast(t);
+        }
+        _ => {
+            q::test_q_i32(t);
+        }
+    }
     t.print("Done!\n");
 }
 
+/// Run all tests of all test groups.
+pub fn run_tests(t: &impl TestOps) {
+    run_test_group::<0>(t);
+    run_test_group::<1>(t);
+    run_test_group::<2>(t);
+    run_test_group::<3>(t);
+    run_test_group::<4>(t);
+    run_test_group::<5>(t);
+    run_test_group::<6>(t);
+    run_test_group::<7>(t);
+    run_test_group::<8>(t);
+    run_test_group::<9>(t);
+    run_test_group::<10>(t);
+    run_test_group::<11>(t);
+    run_test_group::<12>(t);
+    run_test_group::<13>(t);
+    run_test_group::<14>(t);
+}
+
 // vim: ts=4 sw=4 expandtab
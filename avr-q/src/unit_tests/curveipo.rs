@@ -0,0 +1,155 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, test_assert};
+use crate::{Q, Q7p8, Q15p8, UQ8p8, q7p8, q15p8, uq8p8, uq16p8};
+use ::curveipo::{Curve, CurveIpo as _};
+
+fn test_q7p8(t: &impl TestOps) {
+    t.begin("curveipo q7p8");
+
+    let curve = Curve::new([
+        (q7p8!(const -10), q7p8!(const 20)),
+        (q7p8!(const 0), q7p8!(const 0)),
+        (q7p8!(const 10), q7p8!(const 5)),
+        (q7p8!(const 20), q7p8!(const -15)),
+    ]);
+
+    // Before the first and after the last point.
+    test_assert!(t, curve.lin_inter(q7p8!(const -100)) == q7p8!(const 20));
+    test_assert!(t, curve.lin_inter(q7p8!(const -10)) == q7p8!(const 20));
+    test_assert!(t, curve.lin_inter(q7p8!(const 20)) == q7p8!(const -15));
+    test_assert!(t, curve.lin_inter(q7p8!(const 100)) == q7p8!(const -15));
+
+    // Exactly on a point.
+    test_assert!(t, curve.lin_inter(q7p8!(const 0)) == q7p8!(const 0));
+    test_assert!(t, curve.lin_inter(q7p8!(const 10)) == q7p8!(const 5));
+
+    // Between points.
+    test_assert!(t, curve.lin_inter(q7p8!(const -5)) == q7p8!(const 10));
+    test_assert!(t, curve.lin_inter(q7p8!(const 4)) == q7p8!(const 2));
+    test_assert!(t, curve.lin_inter(q7p8!(const 5 / 2)) == q7p8!(const 5 / 4));
+    test_assert!(t, curve.lin_inter(q7p8!(const 15)) == q7p8!(const -5));
+
+    // Zero width segment.
+    let left = (q7p8!(const 3), q7p8!(const 7));
+    let right = (q7p8!(const 3), q7p8!(const 9));
+    test_assert!(t, q7p8!(const 3).lin_inter(&left, &right) == q7p8!(const 7));
+    let x: Q7p8 = q7p8!(const 4);
+    test_assert!(t, x.lin_inter(&left, &right) == q7p8!(const 7));
+}
+
+fn test_q15p8(t: &impl TestOps) {
+    t.begin("curveipo q15p8");
+
+    let curve = Curve::new([
+        (q15p8!(const -1000), q15p8!(const 3000)),
+        (q15p8!(const 0), q15p8!(const 1000)),
+        (q15p8!(const 200), q15p8!(const -600)),
+        (q15p8!(const 1000), q15p8!(const 1000)),
+    ]);
+
+    // Before the first and after the last point.
+    test_assert!(
+        t,
+        curve.lin_inter(q15p8!(const -20000)) == q15p8!(const 3000)
+    );
+    test_assert!(
+        t,
+        curve.lin_inter(q15p8!(const -1000)) == q15p8!(const 3000)
+    );
+    test_assert!(t, curve.lin_inter(q15p8!(const 1000)) == q15p8!(const 1000));
+    test_assert!(
+        t,
+        curve.lin_inter(q15p8!(const 20000)) == q15p8!(const 1000)
+    );
+
+    // Exactly on a point.
+    test_assert!(t, curve.lin_inter(q15p8!(const 0)) == q15p8!(const 1000));
+    test_assert!(t, curve.lin_inter(q15p8!(const 200)) == q15p8!(const -600));
+
+    // Between points.
+    test_assert!(t, curve.lin_inter(q15p8!(const -500)) == q15p8!(const 2000));
+    test_assert!(t, curve.lin_inter(q15p8!(const 100)) == q15p8!(const 200));
+    test_assert!(t, curve.lin_inter(q15p8!(const 1 / 2)) == q15p8!(const 996));
+    test_assert!(t, curve.lin_inter(q15p8!(const 600)) == q15p8!(const 200));
+
+    // Zero width segment.
+    let left = (q15p8!(const 300), q15p8!(const -7000));
+    let right = (q15p8!(const 300), q15p8!(const 9000));
+    test_assert!(
+        t,
+        q15p8!(const 300).lin_inter(&left, &right) == q15p8!(const -7000)
+    );
+    let x: Q15p8 = q15p8!(const 400);
+    test_assert!(t, x.lin_inter(&left, &right) == q15p8!(const -7000));
+}
+
+fn test_q(t: &impl TestOps) {
+    t.begin("curveipo q");
+
+    type Q3p12 = Q<i16, 12>;
+    let q = Q3p12::from_fraction;
+    let curve = Curve::new([(q(-2, 1), q(1, 1)), (q(0, 1), q(0, 1)), (q(2, 1), q(-3, 1))]);
+    test_assert!(t, curve.lin_inter(q(-4, 1)) == q(1, 1));
+    test_assert!(t, curve.lin_inter(q(-1, 1)) == q(1, 2));
+    test_assert!(t, curve.lin_inter(q(1, 1)) == q(-3, 2));
+    test_assert!(t, curve.lin_inter(q(4, 1)) == q(-3, 1));
+
+    type Q15p16 = Q<i32, 16>;
+    let q = Q15p16::from_fraction;
+    let left = (q(100, 1), q(-200, 1));
+    let right = (q(300, 1), q(200, 1));
+    test_assert!(t, q(150, 1).lin_inter(&left, &right) == q(-100, 1));
+    test_assert!(t, q(100, 1).lin_inter(&left, &left) == q(-200, 1));
+}
+
+fn test_unsigned(t: &impl TestOps) {
+    t.begin("curveipo unsigned");
+
+    let curve = Curve::new([
+        (uq8p8!(const 0), uq8p8!(const 10)),
+        (uq8p8!(const 10), uq8p8!(const 0)),
+        (uq8p8!(const 20), uq8p8!(const 50)),
+        (uq8p8!(const 40), uq8p8!(const 50)),
+    ]);
+    test_assert!(t, curve.lin_inter(uq8p8!(const 0)) == uq8p8!(const 10));
+    test_assert!(t, curve.lin_inter(uq8p8!(const 5)) == uq8p8!(const 5));
+    test_assert!(
+        t,
+        curve.lin_inter(uq8p8!(const 5 / 2)) == uq8p8!(const 15 / 2)
+    );
+    test_assert!(t, curve.lin_inter(uq8p8!(const 15)) == uq8p8!(const 25));
+    test_assert!(t, curve.lin_inter(uq8p8!(const 30)) == uq8p8!(const 50));
+    test_assert!(t, curve.lin_inter(uq8p8!(const 200)) == uq8p8!(const 50));
+    let left = (uq8p8!(const 3), uq8p8!(const 7));
+    let x: UQ8p8 = uq8p8!(const 3);
+    test_assert!(t, x.lin_inter(&left, &left) == uq8p8!(const 7));
+
+    let curve = Curve::new([
+        (uq16p8!(const 100), uq16p8!(const 40000)),
+        (uq16p8!(const 300), uq16p8!(const 0)),
+        (uq16p8!(const 500), uq16p8!(const 1000)),
+    ]);
+    test_assert!(t, curve.lin_inter(uq16p8!(const 0)) == uq16p8!(const 40000));
+    test_assert!(
+        t,
+        curve.lin_inter(uq16p8!(const 200)) == uq16p8!(const 20000)
+    );
+    test_assert!(t, curve.lin_inter(uq16p8!(const 400)) == uq16p8!(const 500));
+    test_assert!(
+        t,
+        curve.lin_inter(uq16p8!(const 1000)) == uq16p8!(const 1000)
+    );
+}
+
+pub fn test_curveipo(t: &impl TestOps) {
+    t.print("curveipo\n");
+    test_q7p8(t);
+    test_q15p8(t);
+    test_q(t);
+    test_unsigned(t);
+}
+
+// vim: ts=4 sw=4 expandtab
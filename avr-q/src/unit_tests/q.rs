@@ -0,0 +1,422 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, div_round_ref, test_assert};
+use crate::{Q, Q7p8, Q15p8, Wrapping, q7p8, q15p8};
+use avr_int24::I24;
+
+/// Number of raw test values returned by [sample].
+const NR_SAMPLES: i64 = 34;
+
+/// Get a raw test value out of the range `min..=max`.
+/// `one` is the raw representation of 1.0.
+fn sample(i: i64, min: i64, max: i64, one: i64) -> i64 {
+    let v = match i {
+        0..24 => min + (max - min) / 24 * i,
+        24 => max,
+        25 => max - 1,
+        26 => min + 1,
+        27 => 0,
+        28 => 1,
+        29 => -1,
+        30 => one,
+        31 => -one,
+        32 => one + 1,
+        _ => one / 2 + 1,
+    };
+    v.clamp(min, max)
+}
+
+/// Raw reference result of a checked operation that returns `None`.
+const NONE: i64 = i64::MIN;
+
+/// Calculate the raw reference results of an exact raw value `v`
+/// of a [Q] type with `bits` storage bits.
+///
+/// Returns the saturated value, the overflow flag as 0 or 1,
+/// the value of a checked operation and the wrapped value.
+#[inline(never)]
+fn range_ref(v: i64, bits: u32) -> [i64; 4] {
+    let min: i64 = -(1 << (bits - 1));
+    let max: i64 = (1 << (bits - 1)) - 1;
+    let ovf = v < min || v > max;
+    [
+        v.clamp(min, max),
+        ovf as i64,
+        if ovf { NONE } else { v },
+        ((v - min) & ((1 << bits) - 1)) + min,
+    ]
+}
+
+/// Calculate the raw reference results of the binary operations on the raw values `a` and `b`
+/// of a [Q] type with `bits` storage bits and `frac` fractional bits.
+///
+/// The results are in the order of the operations in [check_ref].
+/// This is not a part of [check_ref],
+/// so that there is only one copy of the 64 bit reference in the target test image.
+#[inline(never)]
+fn binary_ref(a: i64, b: i64, bits: u32, frac: u32) -> [[i64; 4]; 5] {
+    let p = a * b;
+    let n = a << frac;
+    let round = |v: i64| range_ref(v, bits)[0];
+    let (div, div_round, div_even) = if b == 0 {
+        // Division by zero saturates.
+        let q = if a < 0 {
+            -(1 << (bits - 1))
+        } else {
+            (1 << (bits - 1)) - 1
+        };
+        ([q, 1, NONE, q], q, q)
+    } else {
+        (
+            range_ref(n / b, bits),
+            round(div_round_ref(n, b, false)),
+            round(div_round_ref(n, b, true)),
+        )
+    };
+    [
+        range_ref(a + b, bits),
+        range_ref(a - b, bits),
+        range_ref(p >> frac, bits),
+        div,
+        [
+            round(div_round_ref(p, 1 << frac, false)),
+            round(div_round_ref(p, 1 << frac, true)),
+            div_round,
+            div_even,
+        ],
+    ]
+}
+
+/// Calculate the raw reference results of the unary operations on the raw value `a`
+/// of a [Q] type with `bits` storage bits and `frac` fractional bits.
+///
+/// The results are in the order of the operations in [check_ref].
+#[inline(never)]
+fn unary_ref(a: i64, bits: u32, frac: u32) -> [[i64; 4]; 2] {
+    let [neg, _, neg_checked, neg_wrap] = range_ref(-a, bits);
+    let [abs, _, abs_checked, _] = range_ref(a.abs(), bits);
+    [
+        [neg, neg, neg_checked, neg_wrap],
+        [abs, abs_checked, a >> frac, a],
+    ]
+}
+
+/// Check all arithmetic operations of a [Q] type against the 64 bit reference
+/// of [binary_ref] and [unary_ref].
+/// The const variants are checked against the optimized variants.
+macro_rules! check_ref {
+    ($t:expr, $storage:ty, $frac:literal, $bits:literal, $from:expr, $to:expr) => {{
+        type T = Q<$storage, $frac>;
+        let from: fn(i64) -> $storage = $from;
+        let to: fn($storage) -> i64 = $to;
+        let min: i64 = -(1 << ($bits - 1));
+        let max: i64 = (1 << ($bits - 1)) - 1;
+        let one: i64 = 1 << $frac;
+        let q = |v: i64| T::from_q(from(v));
+        let v = |x: T| to(x.to_q());
+        let c = |x: Option<T>| x.map_or(NONE, v);
+
+        let mut ok = true;
+        for i in 0..NR_SAMPLES {
+            let a = sample(i, min, max, one);
+            let qa = q(a);
+            for j in 0..NR_SAMPLES {
+                let b = sample(j, min, max, one);
+                let qb = q(b);
+                let add = qa.overflowing_add(qb);
+                let sub = qa.overflowing_sub(qb);
+                let mul = qa.overflowing_mul(qb);
+                let div = qa.overflowing_div(qb);
+                let r = [
+                    [
+                        v(add.0),
+                        add.1 as i64,
+                        c(qa.checked_add(qb)),
+                        v(qa.wrapping_add(qb)),
+                    ],
+                    [
+                        v(sub.0),
+                        sub.1 as i64,
+                        c(qa.checked_sub(qb)),
+                        v(qa.wrapping_sub(qb)),
+                    ],
+                    [
+                        v(mul.0),
+                        mul.1 as i64,
+                        c(qa.checked_mul(qb)),
+                        v(qa.wrapping_mul(qb)),
+                    ],
+                    [
+                        v(div.0),
+                        div.1 as i64,
+                        c(qa.checked_div(qb)),
+                        v(qa.wrapping_div(qb)),
+                    ],
+                    [
+                        v(qa.mul_round(qb)),
+                        v(qa.mul_round_even(qb)),
+                        v(qa.div_round(qb)),
+                        v(qa.div_round_even(qb)),
+                    ],
+                ];
+                ok &= r == binary_ref(a, b, $bits, $frac);
+                ok &= qa + qb == add.0 && qa.add(qb) == add.0;
+                ok &= qa - qb == sub.0 && qa.sub(qb) == sub.0;
+                ok &= qa * qb == mul.0 && qa / qb == div.0;
+                ok &= Wrapping(qa) + Wrapping(qb) == Wrapping(qa.wrapping_add(qb));
+                ok &= Wrapping(qa) / Wrapping(qb) == Wrapping(qa.wrapping_div(qb));
+
+                ok &= qa.const_overflowing_mul(qb) == mul;
+                ok &= qa.const_mul(qb) == mul.0;
+                ok &= qa.const_checked_mul(qb) == qa.checked_mul(qb);
+                ok &= qa.const_mul_round(qb) == qa.mul_round(qb);
+                ok &= qa.const_mul_round_even(qb) == qa.mul_round_even(qb);
+                ok &= qa.const_overflowing_div(qb) == div;
+                ok &= qa.const_div(qb) == div.0;
+                ok &= qa.const_checked_div(qb) == qa.checked_div(qb);
+                ok &= qa.const_div_round(qb) == qa.div_round(qb);
+                ok &= qa.const_div_round_even(qb) == qa.div_round_even(qb);
+            }
+
+            let r = [
+                [
+                    v(-qa),
+                    v(qa.neg()),
+                    c(qa.checked_neg()),
+                    v(qa.wrapping_neg()),
+                ],
+                [v(qa.abs()), c(qa.checked_abs()), qa.to_int() as i64, v(qa)],
+            ];
+            ok &= r == unary_ref(a, $bits, $frac);
+        }
+        test_assert!($t, ok);
+    }};
+}
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    type Q3p12 = Q<i16, 12>;
+    test_assert!(t, Q3p12::SHIFT == 12);
+    test_assert!(t, Q3p12::from_int(3).to_q() == 3 << 12);
+    test_assert!(t, Q3p12::from_int(-8).to_q() == i16::MIN);
+    test_assert!(t, Q3p12::from_int(8).to_q() == i16::MAX);
+    test_assert!(t, Q3p12::from_int(-9).to_q() == i16::MIN);
+    test_assert!(t, Q3p12::from_int(-3).to_int() == -3);
+    test_assert!(t, Q3p12::from_fraction(1, 4).to_q() == 1 << 10);
+    test_assert!(t, Q3p12::from_fraction(-7, 2).to_q() == -7 << 11);
+    test_assert!(t, Q3p12::from_fraction(-7, 2).to_int() == -4);
+    test_assert!(t, Q3p12::from_fraction(17, 2).to_q() == i16::MAX);
+
+    type Q1p14 = Q<i16, 14>;
+    test_assert!(t, Q1p14::from_fraction(1, 3).to_q() == 0x1555);
+    test_assert!(t, Q1p14::from_int(1).to_q() == 1 << 14);
+
+    type Q11p4 = Q<i16, 4>;
+    test_assert!(t, Q11p4::from_int(2047).to_q() == 2047 << 4);
+    test_assert!(t, Q11p4::from_int(-2048).to_q() == i16::MIN);
+    test_assert!(t, Q11p4::from_int(2048).to_q() == i16::MAX);
+
+    type Q0p15 = Q<i16, 15>;
+    test_assert!(t, Q0p15::from_int(-1).to_q() == i16::MIN);
+    test_assert!(t, Q0p15::from_fraction(1, 2).to_q() == 1 << 14);
+
+    type Q3p4 = Q<i8, 4>;
+    test_assert!(t, Q3p4::from_int(7).to_q() == 7 << 4);
+    test_assert!(t, Q3p4::from_int(8).to_q() == i8::MAX);
+    test_assert!(t, Q3p4::from_fraction(-1, 16).to_q() == -1);
+
+    type Q11p12 = Q<I24, 12>;
+    test_assert!(
+        t,
+        Q11p12::from_int(2047).to_q() == I24::from_i32(2047 << 12)
+    );
+    test_assert!(
+        t,
+        Q11p12::from_int(-2049).to_q() == I24::from_i32(-0x80_0000)
+    );
+    test_assert!(
+        t,
+        Q11p12::from_fraction(5, 4).to_q() == I24::from_i32(5 << 10)
+    );
+    test_assert!(
+        t,
+        Q11p12::from_int(i32::MAX).to_q() == I24::from_i32(0x7F_FFFF)
+    );
+    test_assert!(
+        t,
+        Q11p12::from_int(i32::MIN).to_q() == I24::from_i32(-0x80_0000)
+    );
+
+    type Q15p16 = Q<i32, 16>;
+    test_assert!(t, Q15p16::from_int(-32768).to_q() == i32::MIN);
+    test_assert!(t, Q15p16::from_int(32768).to_q() == i32::MAX);
+    test_assert!(t, Q15p16::from_int(i32::MIN).to_q() == i32::MIN);
+    test_assert!(t, Q15p16::from_fraction(1, 0).to_q() == i32::MAX);
+    test_assert!(t, Q15p16::from_fraction(0, 0).to_q() == i32::MAX);
+    test_assert!(t, Q15p16::from_fraction(-1, 0).to_q() == i32::MIN);
+}
+
+fn test_arith(t: &impl TestOps) {
+    t.begin("arith");
+
+    type Q3p12 = Q<i16, 12>;
+    let a = Q3p12::from_fraction(3, 2);
+    let b = Q3p12::from_fraction(-5, 4);
+    test_assert!(t, a + b == Q3p12::from_fraction(1, 4));
+    test_assert!(t, a - b == Q3p12::from_fraction(11, 4));
+    test_assert!(t, a * b == Q3p12::from_fraction(-15, 8));
+    test_assert!(t, a.const_mul(b) == Q3p12::from_fraction(-15, 8));
+    test_assert!(t, b / a == Q3p12::from_fraction(-5, 6));
+    test_assert!(t, b.const_div(a) == Q3p12::from_fraction(-5, 6));
+    test_assert!(t, -b == Q3p12::from_fraction(5, 4));
+    test_assert!(t, b.abs() == Q3p12::from_fraction(5, 4));
+    let c = Q3p12::from_int(3);
+    test_assert!(t, c * c == Q3p12::from_q(i16::MAX));
+    test_assert!(t, c * -c == Q3p12::from_q(i16::MIN));
+    test_assert!(t, c.checked_mul(c).is_none());
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= b;
+    c /= b;
+    test_assert!(t, c == a);
+
+    type Q15p16 = Q<i32, 16>;
+    let a = Q15p16::from_int(300);
+    let b = Q15p16::from_fraction(1, 3);
+    test_assert!(t, a * b == Q15p16::from_q(300 * 0x5555));
+    test_assert!(t, a / Q15p16::from_int(-4) == Q15p16::from_int(-75));
+    test_assert!(
+        t,
+        Q15p16::from_q(3) * Q15p16::from_fraction(1, 2) == Q15p16::from_q(1)
+    );
+    let c = Q15p16::from_q(3).mul_round(Q15p16::from_fraction(1, 2));
+    test_assert!(t, c == Q15p16::from_q(2));
+    test_assert!(t, a * a == Q15p16::from_q(i32::MAX));
+    test_assert!(t, a.overflowing_mul(-a) == (Q15p16::from_q(i32::MIN), true));
+}
+
+fn test_fast(t: &impl TestOps) {
+    t.begin("fast");
+
+    // Q7p8 and Q15p8 are thin wrappers around Q<i16, 8> and Q<I24, 8>.
+    let a: Q<i16, 8> = q7p8!(const 3 / 2).into();
+    test_assert!(t, Q7p8::from(a) == q7p8!(const 3 / 2));
+    let a: Q<I24, 8> = q15p8!(const 3 / 2).into();
+    test_assert!(t, Q15p8::from(a) == q15p8!(const 3 / 2));
+}
+
+fn test_fast_i16(t: &impl TestOps) {
+    t.begin("fast i16");
+
+    // The optimized Q<i16, 8> implementation matches the generic const implementation.
+    let mut ok = true;
+    let mut i: i32 = -0x8000;
+    while i <= 0x7FFF {
+        let mut j: i32 = -0x8000;
+        while j <= 0x7FFF {
+            let qa = Q::<i16, 8>::from_q(i as i16);
+            let qb = Q::<i16, 8>::from_q(j as i16);
+            ok &= qa * qb == qa.const_mul(qb);
+            ok &= qa / qb == qa.const_div(qb);
+            ok &= qa.mul_round(qb) == qa.const_mul_round(qb);
+            ok &= qa.div_round_even(qb) == qa.const_div_round_even(qb);
+            ok &= qa.overflowing_mul(qb) == qa.const_overflowing_mul(qb);
+            ok &= qa.overflowing_div(qb) == qa.const_overflowing_div(qb);
+            j += 0x7F3;
+        }
+        i += 0x6F1;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_fast_i24(t: &impl TestOps) {
+    t.begin("fast I24");
+
+    // The optimized Q<I24, 8> implementation matches the generic const implementation.
+    let mut ok = true;
+    let mut i: i32 = -0x8000;
+    while i <= 0x7FFF {
+        let mut j: i32 = -0x8000;
+        while j <= 0x7FFF {
+            let qa = Q::<I24, 8>::from_q(I24::from_i32(i << 8 | (j & 0xFF)));
+            let qb = Q::<I24, 8>::from_q(I24::from_i32(j << 4));
+            ok &= qa * qb == qa.const_mul(qb);
+            ok &= qa / qb == qa.const_div(qb);
+            ok &= qa.mul_round_even(qb) == qa.const_mul_round_even(qb);
+            ok &= qa.div_round(qb) == qa.const_div_round(qb);
+            ok &= qa.overflowing_mul(qb) == qa.const_overflowing_mul(qb);
+            ok &= qa.overflowing_div(qb) == qa.const_overflowing_div(qb);
+            j += 0x7F3;
+        }
+        i += 0x6F1;
+    }
+    test_assert!(t, ok);
+}
+
+pub fn test_q(t: &impl TestOps) {
+    t.print("q\n");
+    test_base(t);
+    test_arith(t);
+    test_fast(t);
+
+    // The other formats are checked by the test_q_* functions.
+    t.begin("ref");
+    check_ref!(t, i32, 16, 32, |v| v as i32, |v| v as i64);
+}
+
+pub fn test_q_i8(t: &impl TestOps) {
+    t.print("q i8\n");
+    t.begin("ref");
+    check_ref!(t, i8, 0, 8, |v| v as i8, |v| v as i64);
+    check_ref!(t, i8, 5, 8, |v| v as i8, |v| v as i64);
+    check_ref!(t, i8, 7, 8, |v| v as i8, |v| v as i64);
+}
+
+pub fn test_q_i16(t: &impl TestOps) {
+    t.print("q i16\n");
+    t.begin("ref");
+    check_ref!(t, i16, 4, 16, |v| v as i16, |v| v as i64);
+    check_ref!(t, i16, 12, 16, |v| v as i16, |v| v as i64);
+}
+
+/// Test the formats with an optimized AVR implementation of the i16 operations.
+pub fn test_q_i16_fast(t: &impl TestOps) {
+    t.print("q i16 fast\n");
+    test_fast_i16(t);
+    t.begin("ref");
+    check_ref!(t, i16, 8, 16, |v| v as i16, |v| v as i64);
+    check_ref!(t, i16, 15, 16, |v| v as i16, |v| v as i64);
+}
+
+pub fn test_q_i24(t: &impl TestOps) {
+    t.print("q i24\n");
+    t.begin("ref");
+    check_ref!(t, I24, 4, 24, |v| I24::from_i32(v as i32), |v| v.to_i32()
+        as i64);
+    check_ref!(t, I24, 20, 24, |v| I24::from_i32(v as i32), |v| v.to_i32()
+        as i64);
+}
+
+/// Test the format with an optimized AVR implementation of the I24 operations.
+pub fn test_q_i24_fast(t: &impl TestOps) {
+    t.print("q i24 fast\n");
+    test_fast_i24(t);
+    t.begin("ref");
+    check_ref!(t, I24, 8, 24, |v| I24::from_i32(v as i32), |v| v.to_i32()
+        as i64);
+}
+
+pub fn test_q_i32(t: &impl TestOps) {
+    t.print("q i32\n");
+    t.begin("ref");
+    check_ref!(t, i32, 0, 32, |v| v as i32, |v| v as i64);
+    check_ref!(t, i32, 8, 32, |v| v as i32, |v| v as i64);
+    check_ref!(t, i32, 31, 32, |v| v as i32, |v| v as i64);
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,127 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, div_round_ref, test_assert};
+use crate::{Q0p15, Q7p8, Q15p8, q0p15, q7p8, q15p8};
+use avr_int24::I24;
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    let n = 1;
+    let d = 4;
+    let a = q0p15!(const 1 / 4).to_q();
+    let b = q0p15!(1 / 4).to_q();
+    let c = q0p15!(n / d).to_q();
+    test_assert!(t, a == 0x2000 && a == b && b == c);
+
+    let a = q0p15!(const -3 / 4).to_q();
+    test_assert!(t, a == -0x6000);
+
+    test_assert!(t, q0p15!(const -1).to_q() == i16::MIN);
+    test_assert!(t, q0p15!(-1).to_int() == -1);
+    test_assert!(t, q0p15!(const 0).to_q() == 0);
+    test_assert!(t, q0p15!(const 1).to_q() == i16::MAX);
+    test_assert!(t, q0p15!(const 1 / 1).to_q() == i16::MAX);
+    test_assert!(t, q0p15!(const -1 / 2).to_int() == -1);
+    test_assert!(t, q0p15!(const 1 / 2).to_int() == 0);
+    test_assert!(t, Q0p15::SHIFT == 15);
+}
+
+fn test_mul(t: &impl TestOps) {
+    t.begin("mul");
+
+    let a = q0p15!(const 1 / 2);
+    let b = q0p15!(const -1 / 4);
+    test_assert!(t, a * a == q0p15!(const 1 / 4));
+    test_assert!(t, a * b == q0p15!(const -1 / 8));
+    test_assert!(t, b * b == q0p15!(const 1 / 16));
+
+    let min = Q0p15::from_q(i16::MIN);
+    let max = Q0p15::from_q(i16::MAX);
+    test_assert!(t, min * a == q0p15!(const -1 / 2));
+    test_assert!(t, min * max == Q0p15::from_q(-i16::MAX));
+    test_assert!(t, max * max == Q0p15::from_q(i16::MAX - 1));
+    test_assert!(t, min * min == max);
+    test_assert!(t, min.overflowing_mul(q0p15!(const -1)) == (max, true));
+    test_assert!(t, min.checked_mul(q0p15!(const -1)).is_none());
+    test_assert!(t, min.mul_round(q0p15!(const -1)) == max);
+    test_assert!(t, min.const_mul(q0p15!(const -1)) == max);
+
+    // 1 ULP * 1/2 is a tie.
+    let c = Q0p15::from_q(1);
+    test_assert!(t, c * a == Q0p15::from_q(0));
+    test_assert!(t, c.mul_round(a) == Q0p15::from_q(1));
+    test_assert!(t, c.mul_round_even(a) == Q0p15::from_q(0));
+    test_assert!(t, (-c) * a == Q0p15::from_q(-1));
+    test_assert!(t, (-c).mul_round(a) == Q0p15::from_q(-1));
+    test_assert!(t, (-c).mul_round_even(a) == Q0p15::from_q(0));
+
+    // Compare the optimized multiplication to the const variant and to the reference.
+    let mut ok = true;
+    for i in 0..68_i32 {
+        for j in 0..68_i32 {
+            let a = (i * 989 - 0x8000).min(0x7FFF) as i16;
+            let b = (0x7FFF - j * 983).max(-0x8000) as i16;
+            let qa = Q0p15::from_q(a);
+            let qb = Q0p15::from_q(b);
+            let p = a as i64 * b as i64;
+            let c = (p >> 15).clamp(i16::MIN as i64, i16::MAX as i64);
+            let sat = c != p >> 15;
+            ok &= qa.overflowing_mul(qb) == (Q0p15::from_q(c as i16), sat);
+            ok &= qa.const_overflowing_mul(qb) == (Q0p15::from_q(c as i16), sat);
+            ok &= qa * qb == qa.const_mul(qb);
+            let c = div_round_ref(p, 1 << 15, false).min(i16::MAX as i64);
+            ok &= qa.mul_round(qb).to_q() as i64 == c;
+            ok &= qa.mul_round(qb) == qa.const_mul_round(qb);
+            let c = div_round_ref(p, 1 << 15, true).min(i16::MAX as i64);
+            ok &= qa.mul_round_even(qb).to_q() as i64 == c;
+            ok &= qa.mul_round_even(qb) == qa.const_mul_round_even(qb);
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_conv(t: &impl TestOps) {
+    t.begin("conv");
+
+    let a = q0p15!(const 3 / 4);
+    test_assert!(t, a.to_q7p8() == q7p8!(const 3 / 4));
+    test_assert!(t, a.to_q15p8() == q15p8!(const 3 / 4));
+    test_assert!(t, q7p8!(const 3 / 4).to_q0p15() == a);
+    test_assert!(t, q15p8!(const 3 / 4).to_q0p15() == a);
+
+    // The lower fractional bits are rounded towards negative infinity.
+    test_assert!(t, Q0p15::from_q(0x7F).to_q7p8() == Q7p8::from_q(0));
+    test_assert!(t, Q0p15::from_q(-1).to_q7p8() == Q7p8::from_q(-1));
+    test_assert!(
+        t,
+        Q0p15::from_q(-1).to_q15p8() == Q15p8::from_q(I24::from_i32(-1))
+    );
+    test_assert!(t, q0p15!(const -1).to_q7p8() == q7p8!(const -1));
+    test_assert!(t, q0p15!(const -1).to_q15p8() == q15p8!(const -1));
+
+    // The conversion to Q0.15 saturates.
+    test_assert!(t, q7p8!(const 1).to_q0p15() == Q0p15::from_q(i16::MAX));
+    test_assert!(t, q7p8!(const -1).to_q0p15() == Q0p15::from_q(i16::MIN));
+    test_assert!(t, q7p8!(const -2).to_q0p15() == Q0p15::from_q(i16::MIN));
+    test_assert!(t, q15p8!(const 1000).to_q0p15() == Q0p15::from_q(i16::MAX));
+    test_assert!(t, q15p8!(const -1000).to_q0p15() == Q0p15::from_q(i16::MIN));
+
+    let b: Q0p15 = q7p8!(const -1 / 2).into();
+    let c: Q0p15 = q15p8!(const 1 / 4).into();
+    test_assert!(t, b == q0p15!(const -1 / 2) && c == q0p15!(const 1 / 4));
+    let b: Q7p8 = b.into();
+    let c: Q15p8 = c.into();
+    test_assert!(t, b == q7p8!(const -1 / 2) && c == q15p8!(const 1 / 4));
+}
+
+pub fn test_q0p15(t: &impl TestOps) {
+    t.print("q0p15\n");
+    test_base(t);
+    test_mul(t);
+    test_conv(t);
+}
+
+// vim: ts=4 sw=4 expandtab
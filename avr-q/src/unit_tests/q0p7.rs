@@ -0,0 +1,95 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, test_assert};
+use crate::{Q0p7, Q7p8, Q15p8, q0p7, q7p8, q15p8};
+use avr_int24::I24;
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    let n = 1;
+    let d = 4;
+    let a = q0p7!(const 1 / 4).to_q();
+    let b = q0p7!(1 / 4).to_q();
+    let c = q0p7!(n / d).to_q();
+    test_assert!(t, a == 0x20 && a == b && b == c);
+
+    test_assert!(t, q0p7!(const -3 / 4).to_q() == -0x60);
+    test_assert!(t, q0p7!(const -1).to_q() == i8::MIN);
+    test_assert!(t, q0p7!(-1).to_int() == -1);
+    test_assert!(t, q0p7!(const 1).to_q() == i8::MAX);
+    test_assert!(t, q0p7!(const 1 / 2).to_int() == 0);
+    test_assert!(t, Q0p7::SHIFT == 7);
+}
+
+fn test_arith(t: &impl TestOps) {
+    t.begin("arith");
+
+    let a = q0p7!(const 1 / 2);
+    let b = q0p7!(const -1 / 4);
+    test_assert!(t, a + b == q0p7!(const 1 / 4));
+    test_assert!(t, a - b == q0p7!(const 3 / 4));
+    test_assert!(t, a * b == q0p7!(const -1 / 8));
+    test_assert!(t, b / a == q0p7!(const -1 / 2));
+    test_assert!(t, -a == q0p7!(const -1 / 2));
+
+    // The arithmetic operations saturate.
+    let max = Q0p7::from_q(i8::MAX);
+    let min = Q0p7::from_q(i8::MIN);
+    test_assert!(t, a + a == max);
+    test_assert!(t, b - a - a == min);
+    test_assert!(t, min * min == max);
+    test_assert!(t, a / b == min);
+    test_assert!(t, a / q0p7!(const 0) == max);
+    test_assert!(t, -min == max);
+    test_assert!(t, min.checked_mul(min).is_none());
+}
+
+fn test_conv(t: &impl TestOps) {
+    t.begin("conv");
+
+    let a = q0p7!(const -3 / 8);
+    test_assert!(t, a.to_q7p8() == q7p8!(const -3 / 8));
+    test_assert!(t, a.to_q15p8() == q15p8!(const -3 / 8));
+    test_assert!(t, q7p8!(const -3 / 8).to_q0p7() == a);
+    test_assert!(t, q15p8!(const -3 / 8).to_q0p7() == a);
+
+    // The widening conversions are lossless.
+    let mut ok = true;
+    for q in i8::MIN..=i8::MAX {
+        let a = Q0p7::from_q(q);
+        ok &= a.to_q7p8().to_q() == (q as i16) << 1;
+        ok &= a.to_q15p8().to_q() == I24::from_i16((q as i16) << 1);
+        ok &= a.to_q7p8().to_q0p7() == a;
+        ok &= a.to_q15p8().to_q0p7() == a;
+    }
+    test_assert!(t, ok);
+
+    // The lower fractional bits are rounded towards negative infinity.
+    test_assert!(t, Q7p8::from_q(1).to_q0p7() == Q0p7::from_q(0));
+    test_assert!(t, Q7p8::from_q(-1).to_q0p7() == Q0p7::from_q(-1));
+
+    // The narrowing conversions saturate.
+    test_assert!(t, q7p8!(const 1).to_q0p7() == Q0p7::from_q(i8::MAX));
+    test_assert!(t, q7p8!(const -2).to_q0p7() == Q0p7::from_q(i8::MIN));
+    test_assert!(t, q15p8!(const 1000).to_q0p7() == Q0p7::from_q(i8::MAX));
+    test_assert!(t, q15p8!(const -1000).to_q0p7() == Q0p7::from_q(i8::MIN));
+
+    let b: Q0p7 = q7p8!(const -1 / 2).into();
+    let c: Q0p7 = q15p8!(const 1 / 4).into();
+    test_assert!(t, b == q0p7!(const -1 / 2) && c == q0p7!(const 1 / 4));
+    let b: Q7p8 = b.into();
+    let c: Q15p8 = c.into();
+    test_assert!(t, b == q7p8!(const -1 / 2) && c == q15p8!(const 1 / 4));
+}
+
+pub fn test_q0p7(t: &impl TestOps) {
+    t.print("q0p7\n");
+    test_base(t);
+    test_arith(t);
+    test_conv(t);
+}
+
+// vim: ts=4 sw=4 expandtab
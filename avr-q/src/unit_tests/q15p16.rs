@@ -0,0 +1,133 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, div_round_ref, test_assert};
+use crate::{Q7p8, Q15p8, Q15p16, q7p8, q15p8, q15p16, q23p8};
+use avr_int24::I24;
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    let n = 10;
+    let d = 2;
+    let a = q15p16!(const 10 / 2).to_q();
+    let b = q15p16!(10 / 2).to_q();
+    let c = q15p16!(n / d).to_q();
+    let e = q15p16!(const 5).to_q();
+    test_assert!(t, a == 0x5_0000 && a == b && b == c && c == e);
+
+    test_assert!(t, q15p16!(const 1 / 3).to_q() == 0x5555);
+    test_assert!(t, q15p16!(const -1 / 3).to_q() == -0x5555);
+    test_assert!(t, q15p16!(const 32767).to_int() == 32767);
+    test_assert!(t, q15p16!(const -32768).to_q() == i32::MIN);
+    test_assert!(t, q15p16!(const 32768).to_q() == i32::MAX);
+    test_assert!(t, q15p16!(const -3 / 2).to_int() == -2);
+}
+
+fn test_arith(t: &impl TestOps) {
+    t.begin("arith");
+
+    let a = q15p16!(const 300);
+    let b = q15p16!(const 1 / 3);
+    test_assert!(t, a * b == Q15p16::from_q(300 * 0x5555));
+    test_assert!(t, a.mul_round(b) == Q15p16::from_q(300 * 0x5555));
+    test_assert!(t, a / q15p16!(const -4) == q15p16!(const -75));
+    test_assert!(t, a / q15p16!(const 7) == Q15p16::from_q((300 << 16) / 7));
+    test_assert!(
+        t,
+        a.div_round(q15p16!(const 7)) == Q15p16::from_q((300 << 16) / 7 + 1)
+    );
+
+    let max = Q15p16::from_q(i32::MAX);
+    let min = Q15p16::from_q(i32::MIN);
+    test_assert!(t, a * a == max);
+    test_assert!(t, a * (-a) == min);
+    test_assert!(t, a.overflowing_mul(a) == (max, true));
+    test_assert!(t, a.checked_mul(a).is_none());
+    let c = q15p16!(const 30000);
+    test_assert!(t, c / b == max);
+    test_assert!(t, (-c).overflowing_div(b) == (min, true));
+    test_assert!(t, a / q15p16!(const 0) == max);
+    test_assert!(t, (-a) / q15p16!(const 0) == min);
+    test_assert!(t, a.checked_div(q15p16!(const 0)).is_none());
+    test_assert!(t, min / Q15p16::from_q(-0x1_0000) == max);
+    test_assert!(t, min * q15p16!(const -1) == max);
+    test_assert!(t, min.wrapping_mul(q15p16!(const -1)) == min);
+
+    // Compare to the 64 bit reference.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..600 {
+        // Linear congruential pseudo random numbers of varying magnitude.
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = (x as i32) >> (i % 29);
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let b = (x as i32) >> ((i / 29) % 31);
+        let (qa, qb) = (Q15p16::from_q(a), Q15p16::from_q(b));
+        let q = |v: i64| Q15p16::from_q(v.clamp(i32::MIN as i64, i32::MAX as i64) as i32);
+        let sat = |v: i64| v < i32::MIN as i64 || v > i32::MAX as i64;
+
+        let p = a as i64 * b as i64;
+        ok &= qa.overflowing_mul(qb) == (q(p >> 16), sat(p >> 16));
+        ok &= qa.wrapping_mul(qb) == Q15p16::from_q((p >> 16) as i32);
+        ok &= qa.mul_round(qb) == q(div_round_ref(p, 1 << 16, false));
+        ok &= qa.mul_round_even(qb) == q(div_round_ref(p, 1 << 16, true));
+
+        if b != 0 {
+            let n = (a as i64) << 16;
+            ok &= qa.overflowing_div(qb) == (q(n / b as i64), sat(n / b as i64));
+            ok &= qa.div_round(qb) == q(div_round_ref(n, b as i64, false));
+            ok &= qa.div_round_even(qb) == q(div_round_ref(n, b as i64, true));
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_conv(t: &impl TestOps) {
+    t.begin("conv");
+
+    let a = q15p16!(const -3 / 4);
+    test_assert!(t, a.to_q7p8() == q7p8!(const -3 / 4));
+    test_assert!(t, a.to_q15p8() == q15p8!(const -3 / 4));
+    test_assert!(t, a.to_q23p8() == q23p8!(const -3 / 4));
+    test_assert!(t, q7p8!(const -3 / 4).to_q15p16() == a);
+    test_assert!(t, q15p8!(const -3 / 4).to_q15p16() == a);
+    test_assert!(t, q23p8!(const -3 / 4).to_q15p16() == a);
+
+    // The lower fractional bits are rounded towards negative infinity.
+    test_assert!(t, Q15p16::from_q(-1).to_q7p8() == Q7p8::from_q(-1));
+    test_assert!(
+        t,
+        Q15p16::from_q(0xFF).to_q15p8() == Q15p8::from_q(I24::from_i32(0))
+    );
+
+    // The conversions saturate.
+    test_assert!(t, q15p16!(const 1000).to_q7p8() == Q7p8::from_q(i16::MAX));
+    test_assert!(t, q15p16!(const -1000).to_q7p8() == Q7p8::from_q(i16::MIN));
+    test_assert!(t, q15p16!(const -32768).to_q15p8() == q15p8!(const -32768));
+    test_assert!(
+        t,
+        q23p8!(const 40000).to_q15p16() == Q15p16::from_q(i32::MAX)
+    );
+    test_assert!(
+        t,
+        q23p8!(const -40000).to_q15p16() == Q15p16::from_q(i32::MIN)
+    );
+
+    let b: Q15p16 = q7p8!(const 5).into();
+    let c: Q15p16 = q15p8!(const -7).into();
+    test_assert!(t, b == q15p16!(const 5) && c == q15p16!(const -7));
+    let b: Q7p8 = b.into();
+    let c: Q15p8 = c.into();
+    test_assert!(t, b == q7p8!(const 5) && c == q15p8!(const -7));
+}
+
+pub fn test_q15p16(t: &impl TestOps) {
+    t.print("q15p16\n");
+    test_base(t);
+    test_arith(t);
+    test_conv(t);
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
-use super::{TestOps, test_assert};
-use crate::{Q15p8, q15p8};
+use super::{TestOps, div_round_ref, div_round_ref32, powi_ref, test_assert};
+use crate::{Q15p8, Wrapping, q7p8, q15p8};
+use avr_int24::I24;
 
 fn test_base(t: &impl TestOps) {
     t.begin("base");
@@ -45,13 +46,31 @@ fn test_sub(t: &impl TestOps) {
 fn test_mul(t: &impl TestOps) {
     t.begin("mul");
 
-    /*
     let a = q15p8!(const 1000 / 2);
     let b = q15p8!(const 1 / 4);
     let c = q15p8!(const 1000 / 8);
     test_assert!(t, a * b == c);
     test_assert!(t, a.const_mul(b) == c);
-    */
+
+    let a = q15p8!(const -300);
+    let b = q15p8!(const 100);
+    let c = q15p8!(const -30000);
+    test_assert!(t, a * b == c);
+    test_assert!(t, a.const_mul(b) == c);
+
+    let a = Q15p8::from_q(I24::from_i32(-0x101));
+    let b = Q15p8::from_q(I24::from_i32(0x80));
+    let c = Q15p8::from_q(I24::from_i32(-0x81));
+    test_assert!(t, a * b == c);
+    test_assert!(t, a.const_mul(b) == c);
+
+    let a = q15p8!(const 300);
+    let b = q15p8!(const 200);
+    let c = Q15p8::from_q(I24::from_i32(0x7F_FFFF));
+    test_assert!(t, a * b == c);
+    test_assert!(t, a.const_mul(b) == c);
+    test_assert!(t, -a * b == -c - Q15p8::from_q(I24::from_i32(1)));
+    test_assert!(t, (-a).const_mul(b) == -c - Q15p8::from_q(I24::from_i32(1)));
 }
 
 fn test_div(t: &impl TestOps) {
@@ -64,6 +83,73 @@ fn test_div(t: &impl TestOps) {
     test_assert!(t, a.const_div(b) == c);
 }
 
+fn test_div_zero(t: &impl TestOps) {
+    t.begin("div_zero");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+    let max = q(0x7F_FFFF);
+    let min = q(-0x80_0000);
+    let zero = q15p8!(const 0);
+    for (a, c) in [
+        (q15p8!(const 3 / 2), max),
+        (q(1), max),
+        (max, max),
+        (zero, max),
+        (q15p8!(const -3 / 2), min),
+        (q(-1), min),
+        (min, min),
+    ] {
+        test_assert!(t, a / zero == c);
+        test_assert!(t, a.const_div(zero) == c);
+        test_assert!(t, a.div_round(zero) == c);
+        test_assert!(t, a.const_div_round(zero) == c);
+        test_assert!(t, a.div_round_even(zero) == c);
+        test_assert!(t, a.const_div_round_even(zero) == c);
+        test_assert!(t, a.checked_div(zero).is_none());
+        test_assert!(t, a.const_checked_div(zero).is_none());
+        test_assert!(t, a.overflowing_div(zero) == (c, true));
+        test_assert!(t, a.const_overflowing_div(zero) == (c, true));
+        let mut b = a;
+        b /= zero;
+        test_assert!(t, b == c);
+    }
+    test_assert!(t, Q15p8::from_fraction(0, 0) == max);
+    test_assert!(t, Q15p8::const_from_fraction(0, 0) == max);
+    test_assert!(t, Q15p8::from_fraction(-1, 0) == min);
+    test_assert!(t, Q15p8::const_from_fraction(-1, 0) == min);
+
+    // The runtime and the const variants agree on the edge cases.
+    let edges = [
+        -0x80_0000, -0x7F_FFFF, -0x8001, -0x8000, -0x201, -0x100, -0xFF, -2, -1, 0, 1, 2, 0xFF,
+        0x100, 0x1FF, 0x200, 0x8000, 0x40_0000, 0x7F_FFFF,
+    ];
+    for a in edges {
+        for b in edges {
+            let a = q(a);
+            let b = q(b);
+            let (c, sat) = a.const_overflowing_div(b);
+            test_assert!(t, a.overflowing_div(b) == (c, sat));
+            test_assert!(t, a / b == c);
+            test_assert!(t, a.const_div(b) == c);
+            let checked = if sat { None } else { Some(c) };
+            test_assert!(t, a.checked_div(b) == checked);
+            test_assert!(t, a.const_checked_div(b) == checked);
+        }
+    }
+    test_assert!(t, min / q15p8!(const 1) == min);
+    test_assert!(t, min / q15p8!(const -1) == max);
+    test_assert!(t, min / q(-0x80_0000) == q15p8!(const 1));
+    test_assert!(t, max / min == q(-0xFF));
+    test_assert!(t, min / q15p8!(const 2) == q15p8!(const -16384));
+    test_assert!(t, q15p8!(const 30000) / q15p8!(const 1 / 2) == max);
+    test_assert!(t, q15p8!(const -30000) / q15p8!(const 1 / 2) == min);
+    test_assert!(t, q15p8!(const -16384) / q15p8!(const 1 / 2) == min);
+    test_assert!(
+        t,
+        q15p8!(const -16384).overflowing_div(q15p8!(const 1 / 2)) == (min, false)
+    );
+}
+
 fn test_neg(t: &impl TestOps) {
     t.begin("neg");
 
@@ -87,6 +173,900 @@ fn test_abs(t: &impl TestOps) {
     test_assert!(t, a.const_abs() == b);
 }
 
+fn test_checked(t: &impl TestOps) {
+    t.begin("checked");
+
+    let max = Q15p8::from_q(I24::from_i32(0x7F_FFFF));
+    let min = Q15p8::from_q(I24::from_i32(-0x80_0000));
+    let eps = Q15p8::from_q(I24::from_i32(1));
+
+    let a = q15p8!(const 30000);
+    let b = q15p8!(const 2767);
+    let c = q15p8!(const 32767);
+    test_assert!(t, a.checked_add(b) == Some(c));
+    test_assert!(t, max.checked_add(eps).is_none());
+    test_assert!(t, (-a).checked_sub(b) == Some(-c));
+    test_assert!(t, min.checked_sub(eps).is_none());
+    const D: Option<Q15p8> = q15p8!(const 30000).checked_add(q15p8!(const 2767));
+    test_assert!(t, D == Some(c));
+
+    let a = q15p8!(const -128);
+    let b = q15p8!(const 256);
+    let c = q15p8!(const -32768);
+    test_assert!(t, a.checked_mul(b) == Some(c));
+    test_assert!(t, a.const_checked_mul(b) == Some(c));
+    test_assert!(t, a.checked_mul(-b).is_none());
+    test_assert!(t, a.const_checked_mul(-b).is_none());
+
+    let a = q15p8!(const 32767);
+    let b = q15p8!(const 1 / 2);
+    test_assert!(t, max.checked_div(q15p8!(const 1)) == Some(max));
+    test_assert!(t, max.const_checked_div(q15p8!(const 1)) == Some(max));
+    test_assert!(t, min.checked_div(q15p8!(const 1)) == Some(min));
+    test_assert!(t, min.const_checked_div(q15p8!(const 1)) == Some(min));
+    test_assert!(t, a.checked_div(b).is_none());
+    test_assert!(t, a.const_checked_div(b).is_none());
+    test_assert!(t, a.checked_div(q15p8!(const 0)).is_none());
+    test_assert!(t, a.const_checked_div(q15p8!(const 0)).is_none());
+
+    test_assert!(t, min.checked_neg().is_none());
+    test_assert!(t, min.checked_abs().is_none());
+    test_assert!(t, c == min);
+    let a = q15p8!(const -1000);
+    let b = q15p8!(const 1000);
+    test_assert!(t, a.checked_neg() == Some(b));
+    test_assert!(t, a.checked_abs() == Some(b));
+}
+
+fn test_wrapping(t: &impl TestOps) {
+    t.begin("wrapping");
+
+    let a = q15p8!(const 30000);
+    let b = q15p8!(const 3000);
+    let c = q15p8!(const -32536);
+    test_assert!(t, a.wrapping_add(b) == c);
+    test_assert!(t, c.wrapping_sub(b) == a);
+    test_assert!(
+        t,
+        q15p8!(const 300).wrapping_mul(q15p8!(const 200)) == q15p8!(const -5536)
+    );
+    test_assert!(
+        t,
+        q15p8!(const -3 / 2).wrapping_mul(q15p8!(const 1 / 2)) == q15p8!(const -3 / 4)
+    );
+    test_assert!(
+        t,
+        q15p8!(const -32768).wrapping_neg() == q15p8!(const -32768)
+    );
+    test_assert!(t, a.wrapping_neg() == -a);
+    test_assert!(
+        t,
+        q15p8!(const 30000).wrapping_div(q15p8!(const 1 / 2)) == q15p8!(const -5536)
+    );
+    test_assert!(
+        t,
+        q15p8!(const -300).wrapping_div(q15p8!(const 2)) == q15p8!(const -150)
+    );
+
+    let a = Wrapping(a);
+    let b = Wrapping(b);
+    let c = Wrapping(c);
+    test_assert!(t, a + b == c);
+    test_assert!(t, c - b == a);
+    test_assert!(
+        t,
+        Wrapping(q15p8!(const 300)) * Wrapping(q15p8!(const 200)) == Wrapping(q15p8!(const -5536))
+    );
+    test_assert!(t, -a == Wrapping(-a.0));
+    let mut d = a;
+    d += b;
+    test_assert!(t, d == c);
+    d -= b;
+    test_assert!(t, d == a);
+    d *= Wrapping(q15p8!(const 2));
+    test_assert!(t, d == Wrapping(q15p8!(const -5536)));
+    d /= Wrapping(q15p8!(const -2));
+    test_assert!(t, d == Wrapping(q15p8!(const 2768)));
+}
+
+fn test_overflowing(t: &impl TestOps) {
+    t.begin("overflowing");
+
+    let max = Q15p8::from_q(I24::from_i32(0x7F_FFFF));
+    let min = Q15p8::from_q(I24::from_i32(-0x80_0000));
+
+    let a = q15p8!(const 30000);
+    let b = q15p8!(const 2767);
+    test_assert!(t, a.overflowing_add(b) == (q15p8!(const 32767), false));
+    test_assert!(t, a.overflowing_add(a) == (max, true));
+    test_assert!(t, (-a).overflowing_sub(b) == (q15p8!(const -32767), false));
+    test_assert!(t, (-a).overflowing_sub(a) == (min, true));
+    const D: (Q15p8, bool) = q15p8!(const -30000).overflowing_sub(q15p8!(const 30000));
+    test_assert!(t, D == (min, true));
+
+    let a = q15p8!(const -128);
+    let b = q15p8!(const 256);
+    test_assert!(t, a.overflowing_mul(b) == (min, false));
+    test_assert!(t, a.const_overflowing_mul(b) == (min, false));
+    test_assert!(t, a.overflowing_mul(-b) == (max, true));
+    test_assert!(t, a.const_overflowing_mul(-b) == (max, true));
+    test_assert!(t, a.overflowing_mul(b * q15p8!(const 2)) == (min, true));
+    test_assert!(
+        t,
+        a.const_overflowing_mul(b * q15p8!(const 2)) == (min, true)
+    );
+
+    let a = q15p8!(const 30000);
+    let b = q15p8!(const 1 / 2);
+    test_assert!(
+        t,
+        b.overflowing_div(q15p8!(const 2)) == (q15p8!(const 1 / 4), false)
+    );
+    test_assert!(
+        t,
+        b.const_overflowing_div(q15p8!(const 2)) == (q15p8!(const 1 / 4), false)
+    );
+    test_assert!(t, a.overflowing_div(b) == (max, true));
+    test_assert!(t, a.const_overflowing_div(b) == (max, true));
+    test_assert!(t, a.overflowing_div(-b) == (min, true));
+    test_assert!(t, a.const_overflowing_div(-b) == (min, true));
+    test_assert!(t, a.overflowing_div(q15p8!(const 0)) == (max, true));
+    test_assert!(t, a.const_overflowing_div(q15p8!(const 0)) == (max, true));
+}
+
+/// 32 bit reference of the raw Q15p8 multiply-add `(a * b + c * 2^8) / 2^8`,
+/// rounded to nearest and saturated to the 24 bit range.
+/// Ties are rounded to even, if `even` is `true`.
+/// Otherwise ties are rounded away from zero.
+fn mul_add_ref(a: i32, b: i32, c: i32, even: bool) -> i32 {
+    // a * b / 2^8 = a * hi + a * lo / 2^8 with b = hi * 2^8 + lo and 0 <= lo < 2^8.
+    let (hi, lo) = (b >> 8, b & 0xFF);
+    // The result saturates, if (|a| >> 8) * |hi| >= 2^17, because then |a * hi| >= 2^25.
+    // Otherwise all of the following fits into 32 bits.
+    // This doesn't use checked_mul, because the __mulosi4 of compiler_builtins
+    // writes a 32 bit overflow flag to the 16 bit int of the caller on AVR.
+    if (a.unsigned_abs() >> 8) * hi.unsigned_abs() >= 1 << 17 {
+        return if (a < 0) != (hi < 0) {
+            -0x80_0000
+        } else {
+            0x7F_FFFF
+        };
+    }
+    let p = a * lo;
+    let v = a * hi + c + (p >> 8);
+    let r = p & 0xFF;
+    let v = if r > 0x80 || (r == 0x80 && (if even { v & 1 != 0 } else { v >= 0 })) {
+        v + 1
+    } else {
+        v
+    };
+    v.clamp(-0x80_0000, 0x7F_FFFF)
+}
+
+fn test_round(t: &impl TestOps) {
+    t.begin("round");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+
+    // 3/256 * 1/2 = 1.5/256 and 5/256 * 1/2 = 2.5/256 are ties.
+    let b = q15p8!(const 1 / 2);
+    test_assert!(t, q(3) * b == q(1));
+    test_assert!(t, q(3).mul_round(b) == q(2));
+    test_assert!(t, q(3).const_mul_round(b) == q(2));
+    test_assert!(t, q(3).mul_round_even(b) == q(2));
+    test_assert!(t, q(3).const_mul_round_even(b) == q(2));
+    test_assert!(t, q(-5) * b == q(-3));
+    test_assert!(t, q(-5).mul_round(b) == q(-3));
+    test_assert!(t, q(-5).const_mul_round(b) == q(-3));
+    test_assert!(t, q(-5).mul_round_even(b) == q(-2));
+    test_assert!(t, q(-5).const_mul_round_even(b) == q(-2));
+
+    // 2000 / 3 = 666.666 = 170666.67 / 256
+    let a = q15p8!(const 2000);
+    let b = q15p8!(const 3);
+    test_assert!(t, a / b == q(170666));
+    test_assert!(t, a.div_round(b) == q(170667));
+    test_assert!(t, a.const_div_round(b) == q(170667));
+    test_assert!(t, a.div_round_even(b) == q(170667));
+    test_assert!(t, a.const_div_round_even(b) == q(170667));
+    test_assert!(t, (-a).div_round(b) == q(-170667));
+    test_assert!(t, (-a).div_round_even(b) == q(-170667));
+
+    // 3/256 / 2 = 1.5/256 and 5/256 / 2 = 2.5/256 are ties.
+    let b = q15p8!(const 2);
+    test_assert!(t, q(3).div_round(b) == q(2));
+    test_assert!(t, q(3).div_round_even(b) == q(2));
+    test_assert!(t, q(-5).div_round(b) == q(-3));
+    test_assert!(t, q(-5).div_round_even(b) == q(-2));
+    test_assert!(t, q(-5).const_div_round(b) == q(-3));
+    test_assert!(t, q(-5).const_div_round_even(b) == q(-2));
+
+    // Saturation.
+    let a = q15p8!(const 30000);
+    test_assert!(t, a.mul_round(a) == q(0x7F_FFFF));
+    test_assert!(t, a.const_mul_round_even(-a) == q(-0x80_0000));
+    test_assert!(t, a.div_round(q15p8!(const 1 / 2)) == q(0x7F_FFFF));
+    test_assert!(
+        t,
+        a.const_div_round_even(q15p8!(const -1 / 2)) == q(-0x80_0000)
+    );
+
+    // Compare against the reference across the input range.
+    let mut ok = true;
+    let mut a: i32 = -0x80_0000;
+    while a <= 0x7F_FFFF {
+        let mut b: i32 = -0x7F_FFFF;
+        while b <= 0x7F_FFFF {
+            let qa = q(a);
+            let qb = q(b);
+            let m = q(mul_add_ref(a, b, 0, false));
+            let m_even = q(mul_add_ref(a, b, 0, true));
+            ok &= qa.mul_round(qb) == m && qa.const_mul_round(qb) == m;
+            ok &= qa.mul_round_even(qb) == m_even && qa.const_mul_round_even(qb) == m_even;
+            if b != 0 {
+                let sat = |v: i32| q(v.clamp(-0x80_0000, 0x7F_FFFF));
+                let n = a << 8;
+                let d = sat(div_round_ref32(n, b, false));
+                let d_even = sat(div_round_ref32(n, b, true));
+                ok &= qa.div_round(qb) == d && qa.const_div_round(qb) == d;
+                ok &= qa.div_round_even(qb) == d_even && qa.const_div_round_even(qb) == d_even;
+            }
+            // Scan small divisors densely and large ones sparsely.
+            b += if (-0x400..0x400).contains(&b) {
+                0x3B
+            } else {
+                0x3_1F3B
+            };
+        }
+        a += 0x7_F3F1;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_mul_add(t: &impl TestOps) {
+    t.begin("mul_add");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+
+    let a = q15p8!(const 3 / 2);
+    let b = q15p8!(const -5 / 4);
+    let c = q15p8!(const 1 / 8);
+    test_assert!(t, a.mul_add(b, c) == q15p8!(const -7 / 4));
+    test_assert!(t, a.const_mul_add(b, c) == q15p8!(const -7 / 4));
+
+    // 3/256 * 1/2 = 1.5/256 is a tie, which is rounded only once.
+    // Adding -1 moves the tie below zero, so it's rounded away from zero to -255/256.
+    let a = q(3);
+    let b = q15p8!(const 1 / 2);
+    test_assert!(t, a.mul_add(b, q15p8!(const 0)) == q(2));
+    test_assert!(t, a.mul_add(b, q15p8!(const -1)) == q(-255));
+    test_assert!(t, a.const_mul_add(b, q15p8!(const -1)) == q(-255));
+    test_assert!(t, (-a).mul_add(b, q15p8!(const 1)) == q(255));
+
+    // The product doesn't saturate before the addition.
+    let a = q15p8!(const 30000);
+    let b = q15p8!(const 2);
+    let c = q15p8!(const -32767);
+    test_assert!(t, a * b + c == q(0xFF));
+    test_assert!(t, a.mul_add(b, c) == q15p8!(const 27233));
+    test_assert!(t, a.const_mul_add(b, c) == q15p8!(const 27233));
+    test_assert!(t, a.mul_add(a, c) == q(0x7F_FFFF));
+    test_assert!(t, a.mul_add(-a, -c) == q(-0x80_0000));
+    let min = q(-0x80_0000);
+    test_assert!(t, min.mul_add(min, min) == q(0x7F_FFFF));
+    test_assert!(t, min.mul_add(q15p8!(const 1), min) == q(-0x80_0000));
+
+    // Compare against the exact reference.
+    // The products are concentrated around the 24 bit range.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..3000 {
+        // Linear congruential pseudo random numbers of varying magnitude.
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = (x as i32) >> (8 + (i % 23));
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let b = (x as i32) >> (8 + ((i / 23) % 23));
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let c = (x as i32) >> 8;
+        let (qa, qb, qc) = (q(a), q(b), q(c));
+        let r = q(mul_add_ref(a, b, c, false));
+        ok &= qa.mul_add(qb, qc) == r && qa.const_mul_add(qb, qc) == r;
+    }
+    // Products close to the early saturation limit of 2^24.
+    for i in -40_i32..40 {
+        let a = 0x7F_FFFF - i.abs() * 0x1_0101;
+        let b = 0x200 + i * 3;
+        for c in [-0x80_0000, -0x7F_FF01, 0, 0x7F_FFFF] {
+            for (a, b) in [(a, b), (-a, b), (a, -b)] {
+                ok &= q(a).mul_add(q(b), q(c)) == q(mul_add_ref(a, b, c, false));
+            }
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_int(t: &impl TestOps) {
+    t.begin("int");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+
+    let a = q15p8!(const 3 / 4);
+    test_assert!(t, a * 3_i8 == q15p8!(const 9 / 4));
+    test_assert!(t, a * -30000_i16 == q15p8!(const -22500));
+    test_assert!(t, -5_i8 * a == q15p8!(const -15 / 4));
+    test_assert!(t, 10000_i16 * a == q15p8!(const 7500));
+    test_assert!(t, a / 3_i8 == q15p8!(const 1 / 4));
+    test_assert!(t, q15p8!(const 100) / 1000_i16 == q(25));
+    test_assert!(t, 3_i8 / a == q15p8!(const 4));
+    test_assert!(t, -9000_i16 / q15p8!(const 3 / 2) == q15p8!(const -6000));
+    let mut b = a;
+    b *= 2_i8;
+    b /= -3_i16;
+    test_assert!(t, b == q15p8!(const -1 / 2));
+
+    // Dividing by an integer doesn't lose range.
+    test_assert!(t, q(0x7F_FFFF) / 2_i8 == q(0x3F_FFFF));
+    test_assert!(t, q(-0x80_0000) / 0x4000_i16 == q15p8!(const -2));
+
+    // Saturation.
+    let max = q(0x7F_FFFF);
+    let min = q(-0x80_0000);
+    test_assert!(t, a * 20000_i16 == q15p8!(const 15000));
+    test_assert!(t, q15p8!(const 2) * 20000_i16 == max);
+    test_assert!(t, -20000_i16 * q15p8!(const 2) == min);
+    test_assert!(t, min * -1_i8 == max);
+    test_assert!(t, min / -1_i8 == max);
+    test_assert!(t, a / 0_i8 == max);
+    test_assert!(t, q15p8!(const 0) / 0_i16 == max);
+    test_assert!(t, -a / 0_i16 == min);
+    test_assert!(t, 30000_i16 / a == max);
+    test_assert!(t, -30000_i16 / a == min);
+    test_assert!(t, 1_i8 / q15p8!(const 0) == max);
+    test_assert!(t, -1_i8 / q15p8!(const 0) == min);
+    test_assert!(t, -128_i8 / q(-1) == max);
+    test_assert!(t, i16::MIN / q(-1) == max);
+
+    // Compare against the reference.
+    let mut ok = true;
+    let mut a: i32 = -0x80_0000;
+    while a <= 0x7F_FFFF {
+        let qa = q(a);
+        let sat = |v: i64| q(v.clamp(-0x80_0000, 0x7F_FFFF) as i32);
+        let mut n: i16 = i16::MIN;
+        loop {
+            ok &= qa * n == sat(a as i64 * n as i64);
+            if n != 0 {
+                ok &= qa / n == sat(a as i64 / n as i64);
+            }
+            if a != 0 {
+                ok &= n * qa == sat(a as i64 * n as i64);
+                ok &= n / qa == sat(((n as i64) << 16) / a as i64);
+            }
+            match n.checked_add(if (-0x100..0x100).contains(&n) {
+                7
+            } else {
+                0x3F3
+            }) {
+                Some(v) => n = v,
+                None => break,
+            }
+        }
+        // Scan small values densely and large ones sparsely.
+        a += if (-0x1000..0x1000).contains(&a) {
+            0x1F3
+        } else {
+            0x1_F3F1
+        };
+    }
+    test_assert!(t, ok);
+}
+
+fn test_mixed(t: &impl TestOps) {
+    t.begin("mixed");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+
+    let a = q15p8!(const 1000);
+    let b = q7p8!(const -3 / 4);
+    test_assert!(t, a + b == q15p8!(const 3997 / 4));
+    test_assert!(t, b + a == q15p8!(const 3997 / 4));
+    test_assert!(t, a - b == q15p8!(const 4003 / 4));
+    test_assert!(t, b - a == q15p8!(const -4003 / 4));
+    test_assert!(t, a * b == q15p8!(const -750));
+    test_assert!(t, b * a == q15p8!(const -750));
+    test_assert!(t, a / b == q15p8!(const -4000 / 3));
+    test_assert!(t, b / q15p8!(const 1 / 4) == q15p8!(const -3));
+    let mut c = a;
+    c += b;
+    c -= q7p8!(const 1 / 4);
+    c *= q7p8!(const 2);
+    c /= q7p8!(const -2);
+    test_assert!(t, c == q15p8!(const -999));
+
+    // The result is in the wider format and saturates there.
+    let b = q7p8!(const 100);
+    test_assert!(t, b * b.to_q15p8() == q15p8!(const 10000));
+    test_assert!(t, q15p8!(const 32700) + b == q(0x7F_FFFF));
+    test_assert!(t, q15p8!(const -32700) - b == q(-0x80_0000));
+    test_assert!(t, q15p8!(const 400) * b == q(0x7F_FFFF));
+    test_assert!(t, b / q15p8!(const 0) == q(0x7F_FFFF));
+    test_assert!(t, -b / q15p8!(const 0) == q(-0x80_0000));
+}
+
+fn test_sqrt(t: &impl TestOps) {
+    t.begin("sqrt");
+
+    test_assert!(t, q15p8!(const 10000).sqrt() == q15p8!(const 100));
+    test_assert!(t, q15p8!(const 1 / 4).sqrt() == q15p8!(const 1 / 2));
+    test_assert!(t, q15p8!(const 0).sqrt() == q15p8!(const 0));
+    test_assert!(
+        t,
+        q15p8!(const 2).sqrt() == Q15p8::from_q(I24::from_i32(362))
+    );
+    let max = Q15p8::from_q(I24::from_i32(0x7F_FFFF));
+    test_assert!(t, max.sqrt() == Q15p8::from_q(I24::from_i32(46340)));
+    test_assert!(t, max.const_sqrt() == Q15p8::from_q(I24::from_i32(46340)));
+    test_assert!(
+        t,
+        q15p8!(const 1024).checked_sqrt() == Some(q15p8!(const 32))
+    );
+
+    // Negative values.
+    let min = Q15p8::from_q(I24::from_i32(-0x80_0000));
+    test_assert!(t, q15p8!(const -1).sqrt() == q15p8!(const 0));
+    test_assert!(t, min.const_sqrt() == q15p8!(const 0));
+    test_assert!(t, min.checked_sqrt().is_none());
+
+    // The square root is exact and rounded towards zero.
+    let mut ok = true;
+    let mut q: i32 = -0x80_0000;
+    while q <= 0x7F_FFFF {
+        let a = Q15p8::from_q(I24::from_i32(q));
+        let r = a.sqrt();
+        ok &= r == a.const_sqrt();
+        if q < 0 {
+            ok &= r.to_q().to_i32() == 0 && a.checked_sqrt().is_none();
+        } else {
+            let n = (q as i64) << 8;
+            let r = r.to_q().to_i32() as i64;
+            ok &= r * r <= n && (r + 1) * (r + 1) > n;
+            ok &= a.checked_sqrt() == Some(a.sqrt());
+        }
+        // Scan small values densely and large ones sparsely.
+        q += if (-0x1_0000..0x1_0000).contains(&q) {
+            1
+        } else {
+            0x1F3
+        };
+    }
+    for q in 0x7F_0000..=0x7F_FFFF {
+        let r = Q15p8::from_q(I24::from_i32(q)).sqrt().to_q().to_i32() as i64;
+        let n = (q as i64) << 8;
+        ok &= r * r <= n && (r + 1) * (r + 1) > n;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_trig(t: &impl TestOps) {
+    t.begin("trig");
+
+    test_assert!(t, q15p8!(const 0).sin() == q15p8!(const 0));
+    test_assert!(t, q15p8!(const 0).cos() == q15p8!(const 1));
+    let half_pi = Q15p8::from_q(I24::from_i32(402));
+    test_assert!(t, half_pi.sin() == q15p8!(const 1));
+    test_assert!(t, (-half_pi).const_sin() == q15p8!(const -1));
+    test_assert!(t, (half_pi + half_pi).cos() == q15p8!(const -1));
+
+    // Reference values of sin(q / 256) and cos(q / 256) with 16 fractional bits.
+    const REF: [(i32, i32, i32); 10] = [
+        (1, 256, 65536),
+        (804, 63, -65536),
+        (-32768, -47254, -45410),
+        (32767, 47431, -45225),
+        (100000, 57410, 31608),
+        (-1234567, 11902, -64446),
+        (0x7F_FFFF, 60712, 24678),
+        (-0x80_0000, -60808, 24441),
+        (411775, 43, 65536),
+        (-2573000, 48199, -44405),
+    ];
+    let mut ok = true;
+    for (q, sin, cos) in REF {
+        // The error is less than 0.6 LSB.
+        let a = Q15p8::from_q(I24::from_i32(q));
+        ok &= (a.sin().to_q().to_i32() * 256 - sin).abs() < 154;
+        ok &= (a.cos().to_q().to_i32() * 256 - cos).abs() < 154;
+        ok &= a.sin() == a.const_sin() && a.cos() == a.const_cos();
+    }
+    test_assert!(t, ok);
+
+    // The Q15.8 functions are identical to the Q7.8 functions in the Q7.8 range.
+    let mut ok = true;
+    for q in i16::MIN..=i16::MAX {
+        let a = Q15p8::from_q(I24::from_i16(q));
+        let b = crate::Q7p8::from_q(q);
+        ok &= a.sin().to_q().to_i16() == b.sin().to_q();
+        ok &= a.cos().to_q().to_i16() == b.cos().to_q();
+    }
+    // Symmetries.
+    let mut q: i32 = -0x7F_FFFF;
+    while q <= 0x7F_FFFF {
+        let a = Q15p8::from_q(I24::from_i32(q));
+        let (s, c) = (a.sin(), a.cos());
+        ok &= (-a).sin() == -s && (-a).cos() == c;
+        ok &= s == a.const_sin() && c == a.const_cos();
+        let (s, c) = (s.to_q().to_i32(), c.to_q().to_i32());
+        ok &= (s * s + c * c - 0x1_0000).abs() <= 2 * 256;
+        q += 0x3F1;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_inv_trig(t: &impl TestOps) {
+    t.begin("inv_trig");
+
+    let one = q15p8!(const 1);
+    let zero = q15p8!(const 0);
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+    test_assert!(t, one.atan2(one) == q(201));
+    test_assert!(t, zero.atan2(-one) == q(804));
+    test_assert!(t, zero.const_atan2(zero) == zero);
+    test_assert!(t, one.asin() == q(402));
+    test_assert!(t, (-one).const_acos() == q(804));
+    test_assert!(t, q15p8!(const 1000).acos() == zero);
+    test_assert!(t, q15p8!(const -1000).asin() == q(-402));
+
+    // Reference values of atan2(y, x) with 16 fractional bits.
+    const ATAN2: [(i32, i32, i32); 7] = [
+        (0x7F_FFFF, -0x80_0000, 154416),
+        (-0x80_0000, -0x80_0000, -154416),
+        (1, -0x80_0000, 205887),
+        (-1, -0x80_0000, -205887),
+        (0x7F_FFFF, 1, 102944),
+        (-3000000, 123456, -100248),
+        (5, 7, 40649),
+    ];
+    // Reference values of atan(q / 256) with 16 fractional bits.
+    const ATAN: [(i32, i32); 5] = [
+        (0x7F_FFFF, 102942),
+        (-0x80_0000, -102942),
+        (100000, 102776),
+        (-70000, -102704),
+        (300, 56647),
+    ];
+    // The error is less than 0.6 LSB.
+    let close = |a: Q15p8, r: i32| (a.to_q().to_i32() * 256 - r).abs() < 154;
+    let mut ok = true;
+    for (y, x, r) in ATAN2 {
+        let (y, x) = (q(y), q(x));
+        ok &= close(y.atan2(x), r) && y.atan2(x) == y.const_atan2(x);
+    }
+    for (v, r) in ATAN {
+        let a = q(v);
+        ok &= close(a.atan(), r) && a.atan() == a.const_atan();
+    }
+    test_assert!(t, ok);
+
+    // The Q15.8 functions are identical to the Q7.8 functions in the Q7.8 range.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..2000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = (x as i16) >> (i % 16);
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let b = (x as i16) >> ((i / 16) % 16);
+        let (qa, qb) = (q(a as i32), q(b as i32));
+        let (a, b) = (crate::Q7p8::from_q(a), crate::Q7p8::from_q(b));
+        ok &= qa.atan2(qb).to_q().to_i16() == a.atan2(b).to_q();
+        ok &= qa.atan().to_q().to_i16() == a.atan().to_q();
+        ok &= qa.asin().to_q().to_i16() == a.asin().to_q();
+        ok &= qa.acos().to_q().to_i16() == a.acos().to_q();
+    }
+    // The runtime and the const variants are identical and in range.
+    for i in 0..2000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = q((x as i32 >> 8) >> (i % 24));
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let b = q((x as i32 >> 8) >> ((i / 24) % 24));
+        let r = a.atan2(b);
+        ok &= r == a.const_atan2(b) && r.to_q().to_i32().abs() <= 804;
+        ok &= a.atan() == a.const_atan() && a.atan().to_q().to_i32().abs() <= 402;
+        ok &= a.asin() == a.const_asin() && a.acos() == a.const_acos();
+    }
+    test_assert!(t, ok);
+}
+
+fn test_exp(t: &impl TestOps) {
+    t.begin("exp");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+    let max = q(0x7F_FFFF);
+    let min = q(-0x80_0000);
+    test_assert!(t, q15p8!(const 14).exp2() == q15p8!(const 16384));
+    test_assert!(t, q15p8!(const -8).const_exp2() == q(1));
+    test_assert!(t, q15p8!(const 1024).log2() == q15p8!(const 10));
+    test_assert!(
+        t,
+        q15p8!(const 10).pow(q15p8!(const 4)) == q15p8!(const 10000)
+    );
+    test_assert!(
+        t,
+        q15p8!(const 2).const_pow(q15p8!(const 14)) == q15p8!(const 16384)
+    );
+
+    // Out of range results saturate.
+    test_assert!(t, q15p8!(const 11).exp() == max);
+    test_assert!(t, q15p8!(const 15).exp2() == max);
+    test_assert!(t, q15p8!(const 32000).const_exp() == max);
+    test_assert!(t, q15p8!(const -32000).exp() == q15p8!(const 0));
+    test_assert!(t, q15p8!(const 10).pow(q15p8!(const 5)) == max);
+    test_assert!(t, q15p8!(const 0).ln() == min);
+    test_assert!(t, q15p8!(const -1000).log2() == min);
+    test_assert!(t, q15p8!(const 0).pow(q15p8!(const -1)) == max);
+    test_assert!(t, q15p8!(const -3).pow(q15p8!(const 2)) == q15p8!(const 0));
+
+    // Reference values of e^x and 2^x in raw Q.8 representation.
+    const EXP: [(i32, i32); 4] = [(2560, 5638775), (2650, 8014316), (1000, 12726), (-300, 79)];
+    const EXP2: [(i32, i32); 4] = [(3584, 4194304), (3839, 8365926), (3000, 862850), (-1500, 4)];
+    // Reference values of ln(x) and log2(x) in raw Q.8 representation.
+    const LN: [(i32, i32, i32); 4] = [
+        (0x7F_FFFF, 2662, 3840),
+        (1, -1420, -2048),
+        (123456, 1582, 2282),
+        (256000, 1768, 2551),
+    ];
+    // Reference values of b^y in raw Q.8 representation.
+    const POW: [(i32, i32, i32); 6] = [
+        (2560, 1024, 2560000),
+        (512, 3584, 4194304),
+        (25600, 512, 2560000),
+        (2560, -512, 3),
+        (300000, 256, 300000),
+        (655, 2048, 470165),
+    ];
+    let mut ok = true;
+    for (v, r) in EXP {
+        let a = q(v);
+        ok &= (a.exp().to_q().to_i32() - r).abs() <= 1 && a.exp() == a.const_exp();
+    }
+    for (v, r) in EXP2 {
+        let a = q(v);
+        ok &= a.exp2() == q(r) && a.exp2() == a.const_exp2();
+    }
+    for (v, ln, log2) in LN {
+        let a = q(v);
+        ok &= a.ln() == q(ln) && a.ln() == a.const_ln();
+        ok &= a.log2() == q(log2) && a.log2() == a.const_log2();
+    }
+    for (b, y, r) in POW {
+        let (b, y) = (q(b), q(y));
+        ok &= (b.pow(y).to_q().to_i32() - r).abs() <= 1 && b.pow(y) == b.const_pow(y);
+    }
+    test_assert!(t, ok);
+
+    // The Q15.8 functions are identical to the Q7.8 functions in the Q7.8 range.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..2000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = (x as i16) >> (i % 16);
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let b = (x as i16) >> ((i / 16) % 16);
+        let (qa, qb) = (q(a as i32), q(b as i32));
+        let (a, b) = (crate::Q7p8::from_q(a), crate::Q7p8::from_q(b));
+        let same =
+            |x: Q15p8, y: crate::Q7p8| x.to_q().to_i32().clamp(-0x8000, 0x7FFF) == y.to_q() as i32;
+        ok &= same(qa.exp(), a.exp()) && same(qa.exp2(), a.exp2());
+        ok &= same(qa.ln(), a.ln()) && same(qa.log2(), a.log2());
+        ok &= same(qa.pow(qb), a.pow(b));
+    }
+    test_assert!(t, ok);
+}
+
+fn test_powi(t: &impl TestOps) {
+    t.begin("powi");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+    let max = q(0x7F_FFFF);
+    let min = q(-0x80_0000);
+    test_assert!(t, q15p8!(const 1000).powi(0) == q15p8!(const 1));
+    test_assert!(t, q15p8!(const 0).powi(0) == q15p8!(const 1));
+    test_assert!(t, q15p8!(const 2).powi(14) == q15p8!(const 16384));
+    test_assert!(t, q15p8!(const -2).const_powi(13) == q15p8!(const -8192));
+    test_assert!(t, q15p8!(const -1).powi(254) == q15p8!(const 1));
+    test_assert!(t, q15p8!(const 100).square() == q15p8!(const 10000));
+    test_assert!(t, q15p8!(const -30).const_cube() == q15p8!(const -27000));
+    test_assert!(t, q(257).powi(255) == q(692));
+    test_assert!(t, q(300).powi(30) == q(29832));
+    test_assert!(t, q(-1000).const_powi(5) == q(-232831));
+
+    // Saturation only happens for the final result.
+    test_assert!(t, q15p8!(const 2).powi(15) == max);
+    test_assert!(t, q15p8!(const -2).powi(15) == min);
+    test_assert!(t, q15p8!(const 182).square() == max);
+    test_assert!(t, q15p8!(const -33).cube() == min);
+    test_assert!(t, min.powi(255) == min);
+    test_assert!(t, max.const_powi(2) == max);
+
+    let sat = |r: i64| q(r.clamp(-0x80_0000, 0x7F_FFFF) as i32);
+
+    // Compare random powers to the exact reference.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..4000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let v = ((x as i32) << 8) >> (8 + (x >> 8) % 24);
+        let n = match i % 3 {
+            0 => 2,
+            1 => 3,
+            _ => ((x >> 24) as u8) >> ((x >> 20) & 7),
+        };
+        let a = q(v);
+        let p = a.powi(n);
+        ok &= p == a.const_powi(n);
+        if let Some(r) = powi_ref(v, n) {
+            ok &= p == sat(r);
+        }
+        if n == 2 {
+            ok &= a.square() == p && a.const_square() == p;
+        }
+        if n == 3 {
+            ok &= a.cube() == p && a.const_cube() == p;
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_rounding(t: &impl TestOps) {
+    t.begin("rounding");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+    let max = q(0x7F_FFFF);
+    let min = q(-0x80_0000);
+    let a = q15p8!(const 1001 / 2);
+    let b = q15p8!(const -1001 / 2);
+    let c = q15p8!(const -4001 / 4);
+    test_assert!(
+        t,
+        a.floor() == q15p8!(const 500) && b.floor() == q15p8!(const -501)
+    );
+    test_assert!(
+        t,
+        a.ceil() == q15p8!(const 501) && b.ceil() == q15p8!(const -500)
+    );
+    test_assert!(
+        t,
+        a.round() == q15p8!(const 501) && b.round() == q15p8!(const -501)
+    );
+    test_assert!(
+        t,
+        a.trunc() == q15p8!(const 500) && b.trunc() == q15p8!(const -500)
+    );
+    test_assert!(
+        t,
+        a.fract() == q15p8!(const 1 / 2) && b.fract() == q15p8!(const -1 / 2)
+    );
+    test_assert!(
+        t,
+        c.round() == q15p8!(const -1000) && c.fract() == q15p8!(const -1 / 4)
+    );
+    test_assert!(t, a.to_int_round() == 501 && b.to_int_round() == -501);
+    test_assert!(t, a.to_int_ceil() == 501 && b.to_int_ceil() == -500);
+    test_assert!(t, a.to_int_trunc() == 500 && b.to_int_trunc() == -500);
+    test_assert!(t, c.to_int() == -1001 && c.to_int_trunc() == -1000);
+
+    // Rounding up saturates at the edges.
+    test_assert!(t, max.ceil() == max && max.round() == max);
+    test_assert!(
+        t,
+        max.to_int_ceil() == i16::MAX && max.to_int_round() == i16::MAX
+    );
+    test_assert!(t, max.trunc() == q15p8!(const 32767));
+    test_assert!(
+        t,
+        min.floor() == min && min.ceil() == min && min.round() == min
+    );
+    test_assert!(t, min.trunc() == min && min.fract() == q15p8!(const 0));
+    test_assert!(
+        t,
+        min.to_int_round() == i16::MIN && min.to_int_trunc() == i16::MIN
+    );
+    const C: Q15p8 = q15p8!(const -3 / 4).ceil();
+    test_assert!(t, C == q15p8!(const 0));
+
+    // Compare random values to the reference.
+    let mut ok = true;
+    let sat = |v: i32| q(v.clamp(-0x80_0000, 0x7F_FFFF));
+    let sat_int = |v: i32| (v >> 8).clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    let mut x: u32 = 0x1234_5678;
+    for _ in 0..4000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let v = ((x as i32) << 8) >> (8 + (x >> 8) % 24);
+        let a = q(v);
+        let floor = v.div_euclid(256) * 256;
+        let ceil = -(-v).div_euclid(256) * 256;
+        let round = div_round_ref(v as i64, 256, false) as i32 * 256;
+        let trunc = v / 256 * 256;
+        ok &= a.floor() == sat(floor) && a.ceil() == sat(ceil);
+        ok &= a.round() == sat(round) && a.trunc() == sat(trunc);
+        ok &= a.fract() == sat(v - trunc);
+        ok &= a.to_int() == sat_int(floor) && a.to_int_ceil() == sat_int(ceil);
+        ok &= a.to_int_round() == sat_int(round) && a.to_int_trunc() == sat_int(trunc);
+    }
+    test_assert!(t, ok);
+}
+
+fn test_compare(t: &impl TestOps) {
+    t.begin("compare");
+
+    let q = |v: i32| Q15p8::from_q(I24::from_i32(v));
+    let max = q(0x7F_FFFF);
+    let min = q(-0x80_0000);
+    let a = q15p8!(const 1001 / 2);
+    let b = q15p8!(const -3);
+    test_assert!(
+        t,
+        a.min(b) == b && a.max(b) == a && b.min(a) == b && b.max(a) == a
+    );
+    test_assert!(
+        t,
+        a.clamp(q15p8!(const -100), q15p8!(const 100)) == q15p8!(const 100)
+    );
+    test_assert!(
+        t,
+        b.clamp(q15p8!(const -1), q15p8!(const 1)) == q15p8!(const -1)
+    );
+    test_assert!(
+        t,
+        a.signum() == q15p8!(const 1) && b.signum() == q15p8!(const -1)
+    );
+    test_assert!(t, q15p8!(const 0).signum() == q15p8!(const 0));
+    test_assert!(t, !a.is_negative() && a.is_positive() && !a.is_zero());
+    test_assert!(t, b.is_negative() && !b.is_positive() && !b.is_zero());
+    let z = q15p8!(const 0);
+    test_assert!(t, !z.is_negative() && !z.is_positive() && z.is_zero());
+    test_assert!(
+        t,
+        a.copysign(b) == q15p8!(const -1001 / 2) && b.copysign(a) == q15p8!(const 3)
+    );
+    test_assert!(t, b.copysign(z) == q15p8!(const 3) && z.copysign(b) == z);
+    test_assert!(t, min.copysign(a) == max && min.copysign(b) == min);
+    test_assert!(t, a.abs_diff(b) == crate::UQ16p8::from_q(1007 * 128));
+    test_assert!(t, min.abs_diff(max) == crate::UQ16p8::from_q(0xFF_FFFF));
+
+    // The helpers are usable in const context.
+    const TABLE: [Q15p8; 3] = [
+        q15p8!(const 5000).clamp(q15p8!(const 0), q15p8!(const 2000)),
+        q15p8!(const -5)
+            .copysign(q15p8!(const 1))
+            .max(q15p8!(const 4)),
+        q15p8!(const -300).signum(),
+    ];
+    test_assert!(t, TABLE[0] == q15p8!(const 2000));
+    test_assert!(t, TABLE[1] == q15p8!(const 5));
+    test_assert!(t, TABLE[2] == q15p8!(const -1));
+
+    // Compare random values to the integer reference.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for _ in 0..4000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let va = ((x as i32) << 8) >> 8;
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let vb = ((x as i32) << 8) >> (8 + (x >> 8) % 24);
+        let (a, b) = (q(va), q(vb));
+        ok &= a.min(b) == Ord::min(a, b) && a.max(b) == Ord::max(a, b);
+        let (lo, hi) = (a.min(b), a.max(b));
+        ok &= z.clamp(lo, hi) == Ord::clamp(z, lo, hi);
+        ok &= a.signum().to_q().to_i32() == va.signum() << 8;
+        ok &= a.is_negative() == va.is_negative() && a.is_positive() == va.is_positive();
+        let mag = va.abs().min(0x7F_FFFF);
+        ok &= a.copysign(b).to_q().to_i32() == if vb < 0 { -mag } else { mag };
+        ok &= a.abs_diff(b).to_q() == va.abs_diff(vb);
+    }
+    test_assert!(t, ok);
+}
+
 pub fn test_q15p8(t: &impl TestOps) {
     t.print("q15p8\n");
     test_base(t);
@@ -94,8 +1074,35 @@ pub fn test_q15p8(t: &impl TestOps) {
     test_sub(t);
     test_mul(t);
     test_div(t);
+    test_div_zero(t);
     test_neg(t);
     test_abs(t);
+    test_checked(t);
+    test_wrapping(t);
+    test_overflowing(t);
+    test_compare(t);
+}
+
+pub fn test_q15p8_round(t: &impl TestOps) {
+    t.print("q15p8 round\n");
+    test_round(t);
+    test_mul_add(t);
+    test_int(t);
+    test_mixed(t);
+    test_rounding(t);
+}
+
+pub fn test_q15p8_trig(t: &impl TestOps) {
+    t.print("q15p8 trig\n");
+    test_sqrt(t);
+    test_trig(t);
+    test_inv_trig(t);
+}
+
+pub fn test_q15p8_exp(t: &impl TestOps) {
+    t.print("q15p8 exp\n");
+    test_exp(t);
+    test_powi(t);
 }
 
 // vim: ts=4 sw=4 expandtab
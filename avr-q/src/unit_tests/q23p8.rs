@@ -0,0 +1,96 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, test_assert};
+use crate::{Q7p8, Q15p8, Q15p16, Q23p8, q7p8, q15p8, q15p16, q23p8};
+use avr_int24::I24;
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    let a = q23p8!(const 10 / 2).to_q();
+    let b = q23p8!(10 / 2).to_q();
+    let c = q23p8!(const 5).to_q();
+    test_assert!(t, a == 0x500 && a == b && b == c);
+
+    test_assert!(t, q23p8!(const 3 / 2).to_q() == 0x180);
+    test_assert!(t, q23p8!(const 8_000_000).to_int() == 8_000_000);
+    test_assert!(t, q23p8!(const -8_388_608).to_q() == i32::MIN);
+    test_assert!(t, q23p8!(const 8_388_608).to_q() == i32::MAX);
+}
+
+fn test_arith(t: &impl TestOps) {
+    t.begin("arith");
+
+    let a = q23p8!(const 100000);
+    let b = q23p8!(const 3 / 2);
+    test_assert!(t, a + b == Q23p8::from_q(100001 * 256 + 128));
+    test_assert!(t, a - b == Q23p8::from_q(99998 * 256 + 128));
+    test_assert!(t, a * b == q23p8!(const 150000));
+    test_assert!(t, a / b == Q23p8::from_q(17_066_666));
+    test_assert!(t, a.div_round(b) == Q23p8::from_q(17_066_667));
+    test_assert!(t, -a == q23p8!(const -100000));
+
+    let max = Q23p8::from_q(i32::MAX);
+    let min = Q23p8::from_q(i32::MIN);
+    test_assert!(t, a * a == max);
+    test_assert!(t, (-a).overflowing_mul(a) == (min, true));
+    test_assert!(t, a / Q23p8::from_q(1) == max);
+    test_assert!(t, a / q23p8!(const 0) == max);
+    test_assert!(t, (-a).checked_div(q23p8!(const 0)).is_none());
+    test_assert!(t, max + max == max && min - max == min);
+
+    // 3/256 * 1/2 is a tie.
+    let c = Q23p8::from_q(3);
+    let h = q23p8!(const 1 / 2);
+    test_assert!(t, c * h == Q23p8::from_q(1));
+    test_assert!(t, (-c) * h == Q23p8::from_q(-2));
+    test_assert!(t, (-c).mul_round(h) == Q23p8::from_q(-2));
+    test_assert!(t, (-c).mul_round_even(h) == Q23p8::from_q(-2));
+    test_assert!(t, Q23p8::from_q(5).mul_round_even(h) == Q23p8::from_q(2));
+}
+
+fn test_conv(t: &impl TestOps) {
+    t.begin("conv");
+
+    let a = q23p8!(const -3 / 4);
+    test_assert!(t, a.to_q7p8() == q7p8!(const -3 / 4));
+    test_assert!(t, a.to_q15p8() == q15p8!(const -3 / 4));
+    test_assert!(t, a.to_q15p16() == q15p16!(const -3 / 4));
+    test_assert!(t, q7p8!(const -3 / 4).to_q23p8() == a);
+    test_assert!(t, q15p8!(const -3 / 4).to_q23p8() == a);
+
+    // The conversions saturate.
+    test_assert!(t, q23p8!(const 1000).to_q7p8() == Q7p8::from_q(i16::MAX));
+    test_assert!(t, q23p8!(const -1000).to_q7p8() == Q7p8::from_q(i16::MIN));
+    test_assert!(
+        t,
+        q23p8!(const 100000).to_q15p8() == Q15p8::from_q(I24::from_i32(0x7F_FFFF))
+    );
+    test_assert!(t, q23p8!(const -100000).to_q15p8() == q15p8!(const -32768));
+
+    let b: Q23p8 = q7p8!(const 5).into();
+    let c: Q23p8 = q15p8!(const -7).into();
+    let d: Q23p8 = q15p16!(const 9).into();
+    test_assert!(
+        t,
+        b == q23p8!(const 5) && c == q23p8!(const -7) && d == q23p8!(const 9)
+    );
+    let b: Q7p8 = b.into();
+    let c: Q15p8 = c.into();
+    let d: Q15p16 = d.into();
+    test_assert!(
+        t,
+        b == q7p8!(const 5) && c == q15p8!(const -7) && d == q15p16!(const 9)
+    );
+}
+
+pub fn test_q23p8(t: &impl TestOps) {
+    t.print("q23p8\n");
+    test_base(t);
+    test_arith(t);
+    test_conv(t);
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,103 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, test_assert};
+use crate::{Q3p4, Q7p8, Q15p8, q3p4, q7p8, q15p8};
+use avr_int24::I24;
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    let n = 3;
+    let d = 4;
+    let a = q3p4!(const 3 / 4).to_q();
+    let b = q3p4!(3 / 4).to_q();
+    let c = q3p4!(n / d).to_q();
+    test_assert!(t, a == 0x0C && a == b && b == c);
+
+    test_assert!(t, q3p4!(const -5 / 2).to_q() == -0x28);
+    test_assert!(t, q3p4!(const 7).to_int() == 7);
+    test_assert!(t, q3p4!(-8).to_q() == i8::MIN);
+    test_assert!(t, q3p4!(const 8).to_q() == i8::MAX);
+    test_assert!(t, q3p4!(const -1 / 16).to_int() == -1);
+    test_assert!(t, Q3p4::SHIFT == 4);
+}
+
+fn test_arith(t: &impl TestOps) {
+    t.begin("arith");
+
+    let a = q3p4!(const 3 / 2);
+    let b = q3p4!(const -1 / 4);
+    test_assert!(t, a + b == q3p4!(const 5 / 4));
+    test_assert!(t, a - b == q3p4!(const 7 / 4));
+    test_assert!(t, a * b == q3p4!(const -3 / 8));
+    test_assert!(t, a / b == q3p4!(const -6));
+    test_assert!(t, -a == q3p4!(const -3 / 2));
+
+    // The arithmetic operations saturate.
+    let max = Q3p4::from_q(i8::MAX);
+    let min = Q3p4::from_q(i8::MIN);
+    let c = q3p4!(const 5);
+    test_assert!(t, c + c == max);
+    test_assert!(t, -c - c == min);
+    test_assert!(t, c * c == max);
+    test_assert!(t, c * (-c) == min);
+    test_assert!(t, c / b == min);
+    test_assert!(t, c / q3p4!(const 0) == max);
+    test_assert!(t, -min == max);
+    test_assert!(t, c.checked_add(c).is_none());
+    test_assert!(t, c.overflowing_mul(c) == (max, true));
+    test_assert!(t, c.wrapping_add(c) == Q3p4::from_q(-0x60));
+}
+
+fn test_conv(t: &impl TestOps) {
+    t.begin("conv");
+
+    let a = q3p4!(const -9 / 16);
+    test_assert!(t, a.to_q7p8() == q7p8!(const -9 / 16));
+    test_assert!(t, a.to_q15p8() == q15p8!(const -9 / 16));
+    test_assert!(t, q7p8!(const -9 / 16).to_q3p4() == a);
+    test_assert!(t, q15p8!(const -9 / 16).to_q3p4() == a);
+
+    // The widening conversions are lossless.
+    let mut ok = true;
+    for q in i8::MIN..=i8::MAX {
+        let a = Q3p4::from_q(q);
+        ok &= a.to_q7p8().to_q() == (q as i16) << 4;
+        ok &= a.to_q15p8().to_q() == I24::from_i16((q as i16) << 4);
+        ok &= a.to_q7p8().to_q3p4() == a;
+        ok &= a.to_q15p8().to_q3p4() == a;
+    }
+    test_assert!(t, ok);
+
+    // The lower fractional bits are rounded towards negative infinity.
+    test_assert!(t, Q7p8::from_q(0x0F).to_q3p4() == Q3p4::from_q(0));
+    test_assert!(t, Q7p8::from_q(-1).to_q3p4() == Q3p4::from_q(-1));
+    test_assert!(
+        t,
+        Q15p8::from_q(I24::from_i32(-1)).to_q3p4() == Q3p4::from_q(-1)
+    );
+
+    // The narrowing conversions saturate.
+    test_assert!(t, q7p8!(const 8).to_q3p4() == Q3p4::from_q(i8::MAX));
+    test_assert!(t, q7p8!(const -9).to_q3p4() == Q3p4::from_q(i8::MIN));
+    test_assert!(t, q15p8!(const 1000).to_q3p4() == Q3p4::from_q(i8::MAX));
+    test_assert!(t, q15p8!(const -1000).to_q3p4() == Q3p4::from_q(i8::MIN));
+
+    let b: Q3p4 = q7p8!(const 5 / 2).into();
+    let c: Q3p4 = q15p8!(const -7).into();
+    test_assert!(t, b == q3p4!(const 5 / 2) && c == q3p4!(const -7));
+    let b: Q7p8 = b.into();
+    let c: Q15p8 = c.into();
+    test_assert!(t, b == q7p8!(const 5 / 2) && c == q15p8!(const -7));
+}
+
+pub fn test_q3p4(t: &impl TestOps) {
+    t.print("q3p4\n");
+    test_base(t);
+    test_arith(t);
+    test_conv(t);
+}
+
+// vim: ts=4 sw=4 expandtab
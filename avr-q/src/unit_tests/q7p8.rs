@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
-use super::{TestOps, test_assert};
-use crate::{Q7p8, q7p8};
+use super::{TestOps, div_round_ref, div_round_ref32, powi_ref, test_assert};
+use crate::{Q7p8, Q15p8, Q15p16, Wrapping, q7p8, q15p8, q15p16};
+use avr_int24::I24;
 
 fn test_base(t: &impl TestOps) {
     t.begin("base");
@@ -29,6 +30,8 @@ fn test_add(t: &impl TestOps) {
     let b = q7p8!(const 1 / 3);
     let c = q7p8!(const 8 / 3);
     test_assert!(t, a + b == c);
+    const C: Q7p8 = q7p8!(const 7 / 3).add(q7p8!(const 1 / 3));
+    test_assert!(t, C == c);
 }
 
 fn test_sub(t: &impl TestOps) {
@@ -38,6 +41,8 @@ fn test_sub(t: &impl TestOps) {
     let b = q7p8!(const 1 / 3);
     let c = q7p8!(const 6 / 3);
     test_assert!(t, a - b == c);
+    const C: Q7p8 = q7p8!(const 7 / 3).sub(q7p8!(const 1 / 3));
+    test_assert!(t, C == c);
 }
 
 fn test_mul(t: &impl TestOps) {
@@ -60,6 +65,77 @@ fn test_div(t: &impl TestOps) {
     test_assert!(t, a.const_div(b) == c);
 }
 
+fn test_div_zero(t: &impl TestOps) {
+    t.begin("div_zero");
+
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+    let zero = q7p8!(const 0);
+    for (a, c) in [
+        (q7p8!(const 3 / 2), max),
+        (Q7p8::from_q(1), max),
+        (max, max),
+        (zero, max),
+        (q7p8!(const -3 / 2), min),
+        (Q7p8::from_q(-1), min),
+        (min, min),
+    ] {
+        test_assert!(t, a / zero == c);
+        test_assert!(t, a.const_div(zero) == c);
+        test_assert!(t, a.div_round(zero) == c);
+        test_assert!(t, a.const_div_round(zero) == c);
+        test_assert!(t, a.div_round_even(zero) == c);
+        test_assert!(t, a.const_div_round_even(zero) == c);
+        test_assert!(t, a.checked_div(zero).is_none());
+        test_assert!(t, a.const_checked_div(zero).is_none());
+        test_assert!(t, a.overflowing_div(zero) == (c, true));
+        test_assert!(t, a.const_overflowing_div(zero) == (c, true));
+        let mut b = a;
+        b /= zero;
+        test_assert!(t, b == c);
+    }
+    test_assert!(t, Q7p8::from_fraction(0, 0) == max);
+    test_assert!(t, Q7p8::const_from_fraction(0, 0) == max);
+    test_assert!(t, Q7p8::from_fraction(-1, 0) == min);
+    test_assert!(t, Q7p8::const_from_fraction(-1, 0) == min);
+
+    // The runtime and the const variants agree on the edge cases.
+    let edges = [
+        i16::MIN,
+        i16::MIN + 1,
+        -0x100,
+        -0x80,
+        -2,
+        -1,
+        0,
+        1,
+        2,
+        0x80,
+        0x100,
+        i16::MAX - 1,
+        i16::MAX,
+    ];
+    for a in edges {
+        for b in edges {
+            let a = Q7p8::from_q(a);
+            let b = Q7p8::from_q(b);
+            let (c, sat) = a.const_overflowing_div(b);
+            test_assert!(t, a.overflowing_div(b) == (c, sat));
+            test_assert!(t, a / b == c);
+            test_assert!(t, a.const_div(b) == c);
+            let checked = if sat { None } else { Some(c) };
+            test_assert!(t, a.checked_div(b) == checked);
+            test_assert!(t, a.const_checked_div(b) == checked);
+        }
+    }
+    test_assert!(t, min / Q7p8::from_q(1) == min);
+    test_assert!(t, min / Q7p8::from_q(-1) == max);
+    test_assert!(t, min / q7p8!(const 1) == min);
+    test_assert!(t, min / q7p8!(const -1) == max);
+    test_assert!(t, min / Q7p8::from_q(i16::MIN) == q7p8!(const 1));
+    test_assert!(t, min / q7p8!(const 2) == q7p8!(const -64));
+}
+
 fn test_neg(t: &impl TestOps) {
     t.begin("neg");
 
@@ -78,6 +154,934 @@ fn test_abs(t: &impl TestOps) {
     let a = q7p8!(const -18 / 5);
     let b = q7p8!(const 18 / 5);
     test_assert!(t, a.abs() == b);
+    const B: Q7p8 = q7p8!(const 18 / 5).neg().abs();
+    test_assert!(t, B == b);
+}
+
+fn test_checked(t: &impl TestOps) {
+    t.begin("checked");
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 27);
+    let c = q7p8!(const 127);
+    test_assert!(t, a.checked_add(b) == Some(c));
+    test_assert!(t, c.checked_add(q7p8!(const 1)).is_none());
+    test_assert!(t, (-a).checked_sub(b) == Some(-c));
+    test_assert!(t, (-c).checked_sub(q7p8!(const 2)).is_none());
+
+    let a = q7p8!(const 8);
+    let b = q7p8!(const -16);
+    let c = q7p8!(const -128);
+    test_assert!(t, a.checked_mul(b) == Some(c));
+    test_assert!(t, a.const_checked_mul(b) == Some(c));
+    test_assert!(t, (-a).checked_mul(b).is_none());
+    test_assert!(t, (-a).const_checked_mul(b).is_none());
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 1 / 2);
+    test_assert!(t, b.checked_div(a) == Some(q7p8!(const 1 / 200)));
+    test_assert!(t, b.const_checked_div(a) == Some(q7p8!(const 1 / 200)));
+    test_assert!(t, a.checked_div(b).is_none());
+    test_assert!(t, a.const_checked_div(b).is_none());
+    test_assert!(t, a.checked_div(q7p8!(const 0)).is_none());
+    test_assert!(t, a.const_checked_div(q7p8!(const 0)).is_none());
+
+    let a = Q7p8::from_q(i16::MIN);
+    test_assert!(t, a.checked_neg().is_none());
+    test_assert!(t, a.checked_abs().is_none());
+    test_assert!(t, c == a);
+    let a = q7p8!(const -100);
+    let b = q7p8!(const 100);
+    test_assert!(t, a.checked_neg() == Some(b));
+    test_assert!(t, a.checked_abs() == Some(b));
+}
+
+fn test_wrapping(t: &impl TestOps) {
+    t.begin("wrapping");
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 56);
+    let c = q7p8!(const -100);
+    test_assert!(t, a.wrapping_add(b) == c);
+    test_assert!(t, c.wrapping_sub(b) == a);
+    test_assert!(t, a.wrapping_mul(q7p8!(const 2)) == q7p8!(const -56));
+    test_assert!(
+        t,
+        q7p8!(const -3 / 2).wrapping_mul(q7p8!(const 1 / 2)) == q7p8!(const -3 / 4)
+    );
+    test_assert!(t, q7p8!(const -128).wrapping_neg() == q7p8!(const -128));
+    test_assert!(t, a.wrapping_div(q7p8!(const 1 / 2)) == q7p8!(const -56));
+    test_assert!(t, c.wrapping_div(q7p8!(const 2)) == q7p8!(const -50));
+    test_assert!(t, c.wrapping_div(q7p8!(const 0)) == Q7p8::from_q(i16::MIN));
+    test_assert!(t, a.wrapping_neg() == c);
+
+    let a = Wrapping(a);
+    let b = Wrapping(b);
+    let c = Wrapping(c);
+    test_assert!(t, a + b == c);
+    test_assert!(t, c - b == a);
+    test_assert!(
+        t,
+        a * Wrapping(q7p8!(const 2)) == Wrapping(q7p8!(const -56))
+    );
+    test_assert!(t, -a == c);
+    let mut d = a;
+    d += b;
+    test_assert!(t, d == c);
+    d -= b;
+    test_assert!(t, d == a);
+    d *= Wrapping(q7p8!(const 2));
+    test_assert!(t, d == Wrapping(q7p8!(const -56)));
+    test_assert!(
+        t,
+        a / Wrapping(q7p8!(const 1 / 2)) == Wrapping(q7p8!(const -56))
+    );
+    d /= Wrapping(q7p8!(const -2));
+    test_assert!(t, d == Wrapping(q7p8!(const 28)));
+}
+
+fn test_overflowing(t: &impl TestOps) {
+    t.begin("overflowing");
+
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 27);
+    test_assert!(t, a.overflowing_add(b) == (q7p8!(const 127), false));
+    test_assert!(t, a.overflowing_add(a) == (max, true));
+    test_assert!(t, (-a).overflowing_sub(b) == (q7p8!(const -127), false));
+    test_assert!(t, (-a).overflowing_sub(a) == (min, true));
+
+    let a = q7p8!(const 8);
+    let b = q7p8!(const -16);
+    test_assert!(t, a.overflowing_mul(b) == (min, false));
+    test_assert!(t, a.const_overflowing_mul(b) == (min, false));
+    test_assert!(t, a.overflowing_mul(-b) == (max, true));
+    test_assert!(t, a.const_overflowing_mul(-b) == (max, true));
+    test_assert!(t, a.overflowing_mul(b + b) == (min, true));
+    test_assert!(t, a.const_overflowing_mul(b + b) == (min, true));
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 1 / 2);
+    test_assert!(t, b.overflowing_div(a) == (q7p8!(const 1 / 200), false));
+    test_assert!(
+        t,
+        b.const_overflowing_div(a) == (q7p8!(const 1 / 200), false)
+    );
+    test_assert!(t, a.overflowing_div(b) == (max, true));
+    test_assert!(t, a.const_overflowing_div(b) == (max, true));
+    test_assert!(t, a.overflowing_div(-b) == (min, true));
+    test_assert!(t, a.const_overflowing_div(-b) == (min, true));
+    test_assert!(t, a.overflowing_div(q7p8!(const 0)) == (max, true));
+    test_assert!(t, a.const_overflowing_div(q7p8!(const 0)) == (max, true));
+}
+
+fn test_round(t: &impl TestOps) {
+    t.begin("round");
+
+    // 0.75 * 0.5 = 0.375 = 96 / 256 exactly.
+    // 3/256 * 1/2 = 1.5/256 is a tie.
+    let a = Q7p8::from_q(3);
+    let b = q7p8!(const 1 / 2);
+    test_assert!(t, a * b == Q7p8::from_q(1));
+    test_assert!(t, a.mul_round(b) == Q7p8::from_q(2));
+    test_assert!(t, a.const_mul_round(b) == Q7p8::from_q(2));
+    test_assert!(t, a.mul_round_even(b) == Q7p8::from_q(2));
+    test_assert!(t, a.const_mul_round_even(b) == Q7p8::from_q(2));
+    test_assert!(t, (-a) * b == Q7p8::from_q(-2));
+    test_assert!(t, (-a).mul_round(b) == Q7p8::from_q(-2));
+    test_assert!(t, (-a).const_mul_round(b) == Q7p8::from_q(-2));
+    test_assert!(t, (-a).mul_round_even(b) == Q7p8::from_q(-2));
+    test_assert!(t, (-a).const_mul_round_even(b) == Q7p8::from_q(-2));
+
+    // 5/256 * 1/2 = 2.5/256 is a tie.
+    let a = Q7p8::from_q(5);
+    test_assert!(t, a.mul_round(b) == Q7p8::from_q(3));
+    test_assert!(t, a.const_mul_round(b) == Q7p8::from_q(3));
+    test_assert!(t, a.mul_round_even(b) == Q7p8::from_q(2));
+    test_assert!(t, a.const_mul_round_even(b) == Q7p8::from_q(2));
+    test_assert!(t, (-a).mul_round(b) == Q7p8::from_q(-3));
+    test_assert!(t, (-a).mul_round_even(b) == Q7p8::from_q(-2));
+
+    // 2 / 3 = 0.666 = 170.67 / 256
+    let a = q7p8!(const 2);
+    let b = q7p8!(const 3);
+    test_assert!(t, a / b == Q7p8::from_q(170));
+    test_assert!(t, a.div_round(b) == Q7p8::from_q(171));
+    test_assert!(t, a.const_div_round(b) == Q7p8::from_q(171));
+    test_assert!(t, a.div_round_even(b) == Q7p8::from_q(171));
+    test_assert!(t, a.const_div_round_even(b) == Q7p8::from_q(171));
+    test_assert!(t, (-a).div_round(b) == Q7p8::from_q(-171));
+    test_assert!(t, (-a).div_round_even(b) == Q7p8::from_q(-171));
+
+    // 3/256 / 2 = 1.5/256 and 5/256 / 2 = 2.5/256 are ties.
+    let b = q7p8!(const 2);
+    test_assert!(t, Q7p8::from_q(3).div_round(b) == Q7p8::from_q(2));
+    test_assert!(t, Q7p8::from_q(3).div_round_even(b) == Q7p8::from_q(2));
+    test_assert!(t, Q7p8::from_q(-5).div_round(b) == Q7p8::from_q(-3));
+    test_assert!(t, Q7p8::from_q(-5).div_round_even(b) == Q7p8::from_q(-2));
+    test_assert!(t, Q7p8::from_q(-5).const_div_round(b) == Q7p8::from_q(-3));
+    test_assert!(
+        t,
+        Q7p8::from_q(-5).const_div_round_even(b) == Q7p8::from_q(-2)
+    );
+
+    // Saturation.
+    let a = q7p8!(const 100);
+    test_assert!(t, a.mul_round(a) == Q7p8::from_q(i16::MAX));
+    test_assert!(t, a.const_mul_round_even(-a) == Q7p8::from_q(i16::MIN));
+    test_assert!(t, a.div_round(q7p8!(const 1 / 2)) == Q7p8::from_q(i16::MAX));
+    test_assert!(
+        t,
+        a.const_div_round_even(q7p8!(const -1 / 2)) == Q7p8::from_q(i16::MIN)
+    );
+
+    // Compare against the reference across the input range.
+    let mut ok = true;
+    let mut a: i16 = i16::MIN;
+    loop {
+        let mut b: i16 = -0x3FF;
+        while b <= 0x3FF {
+            let qa = Q7p8::from_q(a);
+            let qb = Q7p8::from_q(b);
+            let sat = |v: i32| Q7p8::from_q(v.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+            let p = a as i32 * b as i32;
+            let m = sat(div_round_ref32(p, 256, false));
+            let m_even = sat(div_round_ref32(p, 256, true));
+            ok &= qa.mul_round(qb) == m && qa.const_mul_round(qb) == m;
+            ok &= qa.mul_round_even(qb) == m_even && qa.const_mul_round_even(qb) == m_even;
+            if b != 0 {
+                let n = (a as i32) << 8;
+                let d = sat(div_round_ref32(n, b as i32, false));
+                let d_even = sat(div_round_ref32(n, b as i32, true));
+                ok &= qa.div_round(qb) == d && qa.const_div_round(qb) == d;
+                ok &= qa.div_round_even(qb) == d_even && qa.const_div_round_even(qb) == d_even;
+            }
+            b += 0x3B;
+        }
+        match a.checked_add(0x7F3) {
+            Some(v) => a = v,
+            None => break,
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_mul_add(t: &impl TestOps) {
+    t.begin("mul_add");
+
+    let a = q7p8!(const 3 / 2);
+    let b = q7p8!(const -5 / 4);
+    let c = q7p8!(const 1 / 8);
+    test_assert!(t, a.mul_add(b, c) == q7p8!(const -7 / 4));
+    test_assert!(t, a.const_mul_add(b, c) == q7p8!(const -7 / 4));
+
+    // 3/256 * 1/2 = 1.5/256 is a tie, which is rounded only once.
+    // Adding -1 moves the tie below zero, so it's rounded away from zero to -255/256.
+    let a = Q7p8::from_q(3);
+    let b = q7p8!(const 1 / 2);
+    test_assert!(t, a.mul_add(b, q7p8!(const 0)) == Q7p8::from_q(2));
+    test_assert!(t, a.mul_add(b, q7p8!(const -1)) == Q7p8::from_q(-255));
+    test_assert!(t, a.const_mul_add(b, q7p8!(const -1)) == Q7p8::from_q(-255));
+
+    // The product doesn't saturate before the addition.
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 2);
+    let c = q7p8!(const -127);
+    test_assert!(t, a * b + c == Q7p8::from_q(255));
+    test_assert!(t, a.mul_add(b, c) == q7p8!(const 73));
+    test_assert!(t, a.const_mul_add(b, c) == q7p8!(const 73));
+    test_assert!(t, a.mul_add(a, c) == Q7p8::from_q(i16::MAX));
+    test_assert!(t, a.mul_add(-a, -c) == Q7p8::from_q(i16::MIN));
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(t, min.mul_add(min, min) == Q7p8::from_q(i16::MAX));
+
+    // Compare against the exact reference.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..3000 {
+        // Linear congruential pseudo random numbers of varying magnitude.
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = (x >> 16) as i16 >> (i % 13);
+        let b = x as i16 >> ((i / 13) % 13);
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let c = (x >> 8) as i16;
+        let (qa, qb, qc) = (Q7p8::from_q(a), Q7p8::from_q(b), Q7p8::from_q(c));
+        let r = div_round_ref32(a as i32 * b as i32 + ((c as i32) << 8), 256, false);
+        let r = Q7p8::from_q(r.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+        ok &= qa.mul_add(qb, qc) == r && qa.const_mul_add(qb, qc) == r;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_int(t: &impl TestOps) {
+    t.begin("int");
+
+    let a = q7p8!(const 3 / 4);
+    test_assert!(t, a * 3_i8 == q7p8!(const 9 / 4));
+    test_assert!(t, a * -100_i16 == q7p8!(const -75));
+    test_assert!(t, -5_i8 * a == q7p8!(const -15 / 4));
+    test_assert!(t, 100_i16 * a == q7p8!(const 75));
+    test_assert!(t, a / 3_i8 == q7p8!(const 1 / 4));
+    test_assert!(t, q7p8!(const 100) / 1000_i16 == Q7p8::from_q(25));
+    test_assert!(t, 3_i8 / a == q7p8!(const 4));
+    test_assert!(t, -90_i16 / q7p8!(const 3 / 2) == q7p8!(const -60));
+    let mut b = a;
+    b *= 2_i8;
+    b /= -3_i16;
+    test_assert!(t, b == q7p8!(const -1 / 2));
+
+    // Dividing by an integer doesn't lose range.
+    test_assert!(
+        t,
+        Q7p8::from_q(i16::MAX) / 2_i8 == Q7p8::from_q(i16::MAX / 2)
+    );
+    test_assert!(t, Q7p8::from_q(i16::MIN) / 256_i16 == q7p8!(const -1 / 2));
+
+    // Saturation.
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(t, a * 200_i16 == max);
+    test_assert!(t, -200_i16 * a == min);
+    test_assert!(t, min * -1_i8 == max);
+    test_assert!(t, min / -1_i8 == max);
+    test_assert!(t, a / 0_i8 == max);
+    test_assert!(t, q7p8!(const 0) / 0_i16 == max);
+    test_assert!(t, -a / 0_i16 == min);
+    test_assert!(t, 200_i16 / a == max);
+    test_assert!(t, -200_i16 / a == min);
+    test_assert!(t, 1_i8 / q7p8!(const 0) == max);
+    test_assert!(t, -1_i8 / q7p8!(const 0) == min);
+    test_assert!(t, -128_i8 / Q7p8::from_q(-1) == max);
+
+    // Compare against the reference.
+    let mut ok = true;
+    let mut a: i16 = i16::MIN;
+    loop {
+        let qa = Q7p8::from_q(a);
+        let sat = |v: i64| Q7p8::from_q(v.clamp(i16::MIN as i64, i16::MAX as i64) as i16);
+        let mut n: i16 = i16::MIN;
+        loop {
+            ok &= qa * n == sat(a as i64 * n as i64);
+            if n != 0 {
+                ok &= qa / n == sat(a as i64 / n as i64);
+            }
+            if a != 0 {
+                ok &= n * qa == sat(a as i64 * n as i64);
+                ok &= n / qa == sat(((n as i64) << 16) / a as i64);
+            }
+            match n.checked_add(if (-0x100..0x100).contains(&n) {
+                7
+            } else {
+                0x3F3
+            }) {
+                Some(v) => n = v,
+                None => break,
+            }
+        }
+        match a.checked_add(0x1F3) {
+            Some(v) => a = v,
+            None => break,
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_widening_mul(t: &impl TestOps) {
+    t.begin("widening_mul");
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 50);
+    test_assert!(t, a * b == Q7p8::from_q(i16::MAX));
+    test_assert!(t, a.widening_mul(b) == q15p8!(const 5000));
+    test_assert!(t, a.const_widening_mul(-b) == q15p8!(const -5000));
+    test_assert!(t, a.widening_mul_exact(b) == q15p16!(const 5000));
+    test_assert!(t, (-a).const_widening_mul_exact(b) == q15p16!(const -5000));
+
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(t, min.widening_mul(min) == q15p8!(const 16384));
+    test_assert!(t, min.widening_mul_exact(min) == q15p16!(const 16384));
+
+    // 3/256 * 1/2 = 1.5/256 is kept exactly in the Q15.16 product.
+    let a = Q7p8::from_q(3);
+    let b = q7p8!(const 1 / 2);
+    test_assert!(t, a.widening_mul(b) == Q15p8::from_q(I24::from_i32(1)));
+    test_assert!(t, (-a).widening_mul(b) == Q15p8::from_q(I24::from_i32(-2)));
+    test_assert!(t, a.widening_mul_exact(b) == Q15p16::from_q(384));
+    test_assert!(t, (-a).widening_mul_exact(b) == Q15p16::from_q(-384));
+
+    // Compare against the reference across the input range.
+    let mut ok = true;
+    let mut a: i16 = i16::MIN;
+    loop {
+        let mut b: i16 = i16::MIN;
+        loop {
+            let (qa, qb) = (Q7p8::from_q(a), Q7p8::from_q(b));
+            let p = a as i32 * b as i32;
+            let w = Q15p8::from_q(I24::from_i32(p >> 8));
+            ok &= qa.widening_mul(qb) == w && qa.const_widening_mul(qb) == w;
+            let e = Q15p16::from_q(p);
+            ok &= qa.widening_mul_exact(qb) == e && qa.const_widening_mul_exact(qb) == e;
+            match b.checked_add(0x1F3) {
+                Some(v) => b = v,
+                None => break,
+            }
+        }
+        match a.checked_add(0x3F1) {
+            Some(v) => a = v,
+            None => break,
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_sqrt(t: &impl TestOps) {
+    t.begin("sqrt");
+
+    test_assert!(t, q7p8!(const 4).sqrt() == q7p8!(const 2));
+    test_assert!(t, q7p8!(const 1 / 4).sqrt() == q7p8!(const 1 / 2));
+    test_assert!(t, q7p8!(const 0).sqrt() == q7p8!(const 0));
+    test_assert!(t, q7p8!(const 2).sqrt() == Q7p8::from_q(362));
+    test_assert!(t, Q7p8::from_q(1).sqrt() == q7p8!(const 1 / 16));
+    test_assert!(t, Q7p8::from_q(i16::MAX).sqrt() == Q7p8::from_q(2896));
+    test_assert!(t, q7p8!(const 9).const_sqrt() == q7p8!(const 3));
+    test_assert!(t, q7p8!(const 9).checked_sqrt() == Some(q7p8!(const 3)));
+
+    // Negative values.
+    test_assert!(t, q7p8!(const -1).sqrt() == q7p8!(const 0));
+    test_assert!(t, Q7p8::from_q(i16::MIN).const_sqrt() == q7p8!(const 0));
+    test_assert!(t, Q7p8::from_q(-1).checked_sqrt().is_none());
+
+    // The square root is exact and rounded towards zero.
+    let mut ok = true;
+    for q in i16::MIN..=i16::MAX {
+        let a = Q7p8::from_q(q);
+        let r = a.sqrt();
+        ok &= r == a.const_sqrt();
+        if q < 0 {
+            ok &= r.to_q() == 0 && a.checked_sqrt().is_none();
+        } else {
+            let n = (q as i32) << 8;
+            let r = r.to_q() as i32;
+            ok &= r * r <= n && (r + 1) * (r + 1) > n;
+            ok &= a.checked_sqrt() == Some(a.sqrt());
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_trig(t: &impl TestOps) {
+    t.begin("trig");
+
+    test_assert!(t, q7p8!(const 0).sin() == q7p8!(const 0));
+    test_assert!(t, q7p8!(const 0).cos() == q7p8!(const 1));
+    test_assert!(t, Q7p8::from_q(402).sin() == q7p8!(const 1));
+    test_assert!(t, Q7p8::from_q(804).cos() == q7p8!(const -1));
+    test_assert!(t, Q7p8::from_q(-402).const_sin() == q7p8!(const -1));
+    test_assert!(t, Q7p8::from_q(804).const_cos() == q7p8!(const -1));
+
+    // Reference values of sin(q / 256) and cos(q / 256) with 16 fractional bits.
+    const REF: [(i16, i32, i32); 20] = [
+        (0, 0, 65536),
+        (1, 256, 65536),
+        (64, 16214, 63499),
+        (128, 31420, 57513),
+        (201, 46330, 46352),
+        (256, 55147, 35409),
+        (402, 65536, 32),
+        (512, 59592, -27273),
+        (804, 63, -65536),
+        (1000, -45370, -47292),
+        (1608, -127, 65536),
+        (-1, -256, 65536),
+        (-300, -60390, 25456),
+        (-804, -63, -65536),
+        (-1206, 65536, -95),
+        (-2000, -65480, 2718),
+        (5000, 41295, 50889),
+        (12345, -58369, -29799),
+        (-32768, -47254, -45410),
+        (32767, 47431, -45225),
+    ];
+    let mut ok = true;
+    for (q, sin, cos) in REF {
+        // The error is less than 0.6 LSB.
+        let a = Q7p8::from_q(q);
+        ok &= ((a.sin().to_q() as i32) * 256 - sin).abs() < 154;
+        ok &= ((a.cos().to_q() as i32) * 256 - cos).abs() < 154;
+        ok &= a.sin() == a.const_sin() && a.cos() == a.const_cos();
+    }
+    test_assert!(t, ok);
+
+    // Symmetries.
+    let mut ok = true;
+    for q in i16::MIN + 1..=i16::MAX {
+        let a = Q7p8::from_q(q);
+        let (s, c) = (a.sin(), a.cos());
+        ok &= s.to_q().abs() <= 256 && c.to_q().abs() <= 256;
+        ok &= (-a).sin() == -s && (-a).cos() == c;
+        ok &= s == a.const_sin() && c == a.const_cos();
+        // sin^2 + cos^2 = 1
+        let n = s.to_q() as i32 * s.to_q() as i32 + c.to_q() as i32 * c.to_q() as i32;
+        ok &= (n - 0x1_0000).abs() <= 2 * 256;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_inv_trig(t: &impl TestOps) {
+    t.begin("inv_trig");
+
+    let one = q7p8!(const 1);
+    let zero = q7p8!(const 0);
+    test_assert!(t, one.atan2(one) == Q7p8::from_q(201));
+    test_assert!(t, zero.atan2(-one) == Q7p8::from_q(804));
+    test_assert!(t, zero.const_atan2(-one) == Q7p8::from_q(804));
+    test_assert!(t, zero.atan2(zero) == zero);
+    test_assert!(t, one.asin() == Q7p8::from_q(402));
+    test_assert!(t, (-one).acos() == Q7p8::from_q(804));
+    test_assert!(t, one.const_acos() == zero);
+    test_assert!(t, zero.atan() == zero && zero.asin() == zero);
+
+    // Values outside of [-1, 1] saturate.
+    test_assert!(t, q7p8!(const 2).asin() == one.asin());
+    test_assert!(t, q7p8!(const -2).const_asin() == (-one).asin());
+    test_assert!(t, q7p8!(const -100).acos() == (-one).acos());
+
+    // Reference values of atan2(y, x) with 16 fractional bits.
+    const ATAN2: [(i16, i16, i32); 14] = [
+        (256, 256, 51472),
+        (-256, 256, -51472),
+        (256, -256, 154416),
+        (-256, -256, -154416),
+        (0, -256, 205887),
+        (0, 256, 0),
+        (256, 0, 102944),
+        (-256, 0, -102944),
+        (100, -3000, 203704),
+        (-7, 20000, -23),
+        (32767, -32768, 154417),
+        (-32768, -32768, -154416),
+        (1, -32768, 205885),
+        (-1, -32768, -205885),
+    ];
+    // Reference values of atan(q / 256) with 16 fractional bits.
+    const ATAN: [(i16, i32); 10] = [
+        (0, 0),
+        (64, 16055),
+        (128, 30386),
+        (256, 51472),
+        (-256, -51472),
+        (512, 72558),
+        (1000, 86519),
+        (-5000, -99591),
+        (32767, 102432),
+        (-32768, -102432),
+    ];
+    // Reference values of asin(q / 256) and acos(q / 256) with 16 fractional bits.
+    const ASIN: [(i16, i32, i32); 12] = [
+        (0, 0, 102944),
+        (1, 256, 102688),
+        (64, 16560, 86384),
+        (128, 34315, 68629),
+        (181, 51465, 51479),
+        (200, 58764, 44180),
+        (255, 97149, 5795),
+        (256, 102944, 0),
+        (-1, -256, 103200),
+        (-128, -34315, 137258),
+        (-255, -97149, 200093),
+        (-256, -102944, 205887),
+    ];
+    // The error is less than 0.6 LSB.
+    let close = |a: Q7p8, r: i32| ((a.to_q() as i32) * 256 - r).abs() < 154;
+    let mut ok = true;
+    for (y, x, r) in ATAN2 {
+        let (y, x) = (Q7p8::from_q(y), Q7p8::from_q(x));
+        ok &= close(y.atan2(x), r) && y.atan2(x) == y.const_atan2(x);
+    }
+    for (q, r) in ATAN {
+        let a = Q7p8::from_q(q);
+        ok &= close(a.atan(), r) && a.atan() == a.const_atan();
+    }
+    for (q, asin, acos) in ASIN {
+        let a = Q7p8::from_q(q);
+        ok &= close(a.asin(), asin) && a.asin() == a.const_asin();
+        ok &= close(a.acos(), acos) && a.acos() == a.const_acos();
+    }
+    test_assert!(t, ok);
+
+    // The runtime and the const variants are identical and in range.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for i in 0..2000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let a = Q7p8::from_q((x as i16) >> (i % 16));
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let b = Q7p8::from_q((x as i16) >> ((i / 16) % 16));
+        let r = a.atan2(b);
+        ok &= r == a.const_atan2(b) && r.to_q().abs() <= 804;
+        ok &= a.atan() == a.const_atan() && a.atan().to_q().abs() <= 402;
+    }
+    for q in -256..=256 {
+        let a = Q7p8::from_q(q);
+        ok &= a.asin() == a.const_asin() && a.asin().to_q().abs() <= 402;
+        ok &= a.acos() == a.const_acos() && (0..=804).contains(&a.acos().to_q());
+    }
+    test_assert!(t, ok);
+
+    // The arcsine matches the angle of the vector `(q, sqrt(1 - q^2))` within 1 LSB.
+    // This reference is only checked on the host.
+    #[cfg(test)]
+    {
+        let mut ok = true;
+        for q in -256..=256_i32 {
+            let c = crate::sqrt::isqrt32((((1 << 16) - q * q) as u32) << 14, 16) as i32;
+            let r = crate::cordic::const_atan2_i24(I24::from_i32(q << 7), I24::from_i32(c));
+            let r = r.to_i32();
+            let a = Q7p8::from_q(q as i16);
+            ok &= (a.asin().to_q() as i32 - r).abs() <= 1;
+            ok &= (a.acos().to_q() as i32 + r - 402).abs() <= 1;
+        }
+        test_assert!(t, ok);
+    }
+}
+
+fn test_exp(t: &impl TestOps) {
+    t.begin("exp");
+
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(t, q7p8!(const 0).exp() == q7p8!(const 1));
+    test_assert!(t, q7p8!(const 3).exp2() == q7p8!(const 8));
+    test_assert!(t, q7p8!(const -3).const_exp2() == q7p8!(const 1 / 8));
+    test_assert!(t, q7p8!(const 1).ln() == q7p8!(const 0));
+    test_assert!(t, q7p8!(const 1 / 4).log2() == q7p8!(const -2));
+    test_assert!(t, q7p8!(const 64).const_log2() == q7p8!(const 6));
+    test_assert!(t, q7p8!(const 2).pow(q7p8!(const 3)) == q7p8!(const 8));
+    test_assert!(
+        t,
+        q7p8!(const 9).const_pow(q7p8!(const 1 / 2)) == q7p8!(const 3)
+    );
+
+    // Out of range results saturate.
+    test_assert!(t, q7p8!(const 5).exp() == max);
+    test_assert!(t, q7p8!(const 7).exp2() == max);
+    test_assert!(t, q7p8!(const 127).const_exp() == max);
+    test_assert!(t, q7p8!(const -20).exp() == q7p8!(const 0));
+    test_assert!(t, q7p8!(const -10).exp2() == q7p8!(const 0));
+    test_assert!(t, q7p8!(const 2).pow(q7p8!(const 7)) == max);
+    test_assert!(t, q7p8!(const 1 / 2).pow(q7p8!(const -7)) == max);
+
+    // Logarithms of non-positive values saturate.
+    test_assert!(t, q7p8!(const 0).ln() == min);
+    test_assert!(t, q7p8!(const -1).log2() == min);
+    test_assert!(t, q7p8!(const -1).const_ln() == min);
+
+    // Powers of zero and of negative bases.
+    let zero = q7p8!(const 0);
+    test_assert!(t, zero.pow(q7p8!(const 2)) == zero);
+    test_assert!(t, zero.pow(zero) == q7p8!(const 1));
+    test_assert!(t, zero.pow(q7p8!(const -1)) == max);
+    test_assert!(t, q7p8!(const -2).pow(q7p8!(const 2)) == zero);
+
+    // Reference values of e^x and 2^x with 16 fractional bits.
+    const EXP: [(i16, i32); 10] = [
+        (0, 65536),
+        (256, 178145),
+        (-256, 24109),
+        (128, 108051),
+        (512, 484249),
+        (1000, 3257938),
+        (-1000, 1318),
+        (1100, 4814920),
+        (-2000, 27),
+        (-4000, 0),
+    ];
+    const EXP2: [(i16, i32); 9] = [
+        (0, 65536),
+        (256, 131072),
+        (-256, 32768),
+        (128, 92682),
+        (1024, 1048576),
+        (1791, 8365926),
+        (-2048, 256),
+        (-2303, 128),
+        (100, 85915),
+    ];
+    // Reference values of ln(x) and log2(x) with 16 fractional bits.
+    const LN: [(i16, i32, i32); 9] = [
+        (1, -363409, -524288),
+        (2, -317983, -458752),
+        (128, -45426, -65536),
+        (256, 0, 0),
+        (512, 45426, 65536),
+        (697, 65641, 94700),
+        (1000, 89298, 128830),
+        (2560, 150902, 217706),
+        (32767, 317981, 458749),
+    ];
+    // Reference values of b^y with 16 fractional bits.
+    const POW: [(i16, i16, i32); 8] = [
+        (512, 768, 524288),
+        (2304, 128, 196608),
+        (640, -256, 26214),
+        (128, -512, 262144),
+        (768, 640, 1021605),
+        (4096, 256, 1048576),
+        (1000, 300, 323555),
+        (64, 1024, 256),
+    ];
+    // The error is less than 0.6 LSB.
+    let close = |a: Q7p8, r: i32| ((a.to_q() as i32) * 256 - r).abs() < 154;
+    let mut ok = true;
+    for (q, r) in EXP {
+        let a = Q7p8::from_q(q);
+        ok &= close(a.exp(), r) && a.exp() == a.const_exp();
+    }
+    for (q, r) in EXP2 {
+        let a = Q7p8::from_q(q);
+        ok &= close(a.exp2(), r) && a.exp2() == a.const_exp2();
+    }
+    for (q, ln, log2) in LN {
+        let a = Q7p8::from_q(q);
+        ok &= close(a.ln(), ln) && a.ln() == a.const_ln();
+        ok &= close(a.log2(), log2) && a.log2() == a.const_log2();
+    }
+    for (b, y, r) in POW {
+        let (b, y) = (Q7p8::from_q(b), Q7p8::from_q(y));
+        ok &= close(b.pow(y), r) && b.pow(y) == b.const_pow(y);
+    }
+    test_assert!(t, ok);
+
+    // The functions are monotonic and the runtime and const variants are identical.
+    // The target only checks every 7th value, because the full sweep takes too long.
+    let mut ok = true;
+    let mut prev = (q7p8!(const 0), min, min);
+    for q in (i16::MIN..=i16::MAX).step_by(if cfg!(test) { 1 } else { 7 }) {
+        let a = Q7p8::from_q(q);
+        let (e, e2, l2) = (a.exp(), a.exp2(), a.log2());
+        ok &= e >= prev.0 && e2 >= prev.1 && l2 >= prev.2;
+        ok &= e == a.const_exp() && e2 == a.const_exp2() && l2 == a.const_log2();
+        ok &= a.ln() == a.const_ln();
+        // 2^log2(x) is close to x.
+        if q >= 256 {
+            ok &= (l2.exp2().to_q() - q).abs() <= 1 + q / 256;
+        }
+        prev = (e, e2, l2);
+    }
+    test_assert!(t, ok);
+}
+
+fn test_powi(t: &impl TestOps) {
+    t.begin("powi");
+
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(t, q7p8!(const 3).powi(0) == q7p8!(const 1));
+    test_assert!(t, q7p8!(const 0).powi(0) == q7p8!(const 1));
+    test_assert!(t, q7p8!(const 0).powi(5) == q7p8!(const 0));
+    test_assert!(t, q7p8!(const -3).powi(1) == q7p8!(const -3));
+    test_assert!(t, q7p8!(const 2).powi(6) == q7p8!(const 64));
+    test_assert!(t, q7p8!(const -2).const_powi(5) == q7p8!(const -32));
+    test_assert!(t, q7p8!(const 1 / 2).powi(8) == Q7p8::from_q(1));
+    test_assert!(t, q7p8!(const 1 / 2).powi(9) == Q7p8::from_q(1));
+    test_assert!(t, q7p8!(const 1 / 2).powi(10) == q7p8!(const 0));
+    test_assert!(t, q7p8!(const -1).powi(255) == q7p8!(const -1));
+    test_assert!(t, q7p8!(const 3 / 2).square() == q7p8!(const 9 / 4));
+    test_assert!(t, q7p8!(const -3 / 2).const_cube() == q7p8!(const -27 / 8));
+
+    // Saturation only happens for the final result.
+    test_assert!(t, q7p8!(const 2).powi(7) == max);
+    test_assert!(t, q7p8!(const -2).powi(7) == min);
+    test_assert!(t, q7p8!(const -2).powi(8) == max);
+    test_assert!(t, q7p8!(const 12).square() == max);
+    test_assert!(t, q7p8!(const -6).const_cube() == min);
+    test_assert!(t, min.powi(255) == min);
+    test_assert!(t, min.const_powi(254) == max);
+    test_assert!(t, Q7p8::from_q(257).powi(255) == Q7p8::from_q(692));
+
+    let sat = |r: i64| Q7p8::from_q(r.clamp(i16::MIN as i64, i16::MAX as i64) as i16);
+
+    // Compare square and cube to the exact reference.
+    // The target only checks every 7th value, because the full sweep takes too long.
+    let mut ok = true;
+    for q in (i16::MIN..=i16::MAX).step_by(if cfg!(test) { 1 } else { 7 }) {
+        let a = Q7p8::from_q(q);
+        let (s, c) = (a.square(), a.cube());
+        ok &= s == sat(powi_ref(q as i32, 2).unwrap()) && s == a.const_square();
+        ok &= c == sat(powi_ref(q as i32, 3).unwrap()) && c == a.const_cube();
+    }
+    test_assert!(t, ok);
+
+    // Compare random powers to the exact reference.
+    // The target only checks the first 500 powers.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for _ in 0..if cfg!(test) { 4000 } else { 500 } {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let q = (x as i16) >> ((x >> 16) & 15);
+        let n = ((x >> 24) as u8) >> ((x >> 20) & 7);
+        let a = Q7p8::from_q(q);
+        let p = a.powi(n);
+        ok &= p == a.const_powi(n);
+        if let Some(r) = powi_ref(q as i32, n) {
+            ok &= p == sat(r);
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_rounding(t: &impl TestOps) {
+    t.begin("rounding");
+
+    let max = Q7p8::from_q(i16::MAX);
+    let a = q7p8!(const 5 / 2);
+    let b = q7p8!(const -5 / 2);
+    let c = q7p8!(const -11 / 4);
+    test_assert!(
+        t,
+        a.floor() == q7p8!(const 2) && b.floor() == q7p8!(const -3)
+    );
+    test_assert!(t, a.ceil() == q7p8!(const 3) && b.ceil() == q7p8!(const -2));
+    test_assert!(
+        t,
+        a.round() == q7p8!(const 3) && b.round() == q7p8!(const -3)
+    );
+    test_assert!(
+        t,
+        a.trunc() == q7p8!(const 2) && b.trunc() == q7p8!(const -2)
+    );
+    test_assert!(
+        t,
+        a.fract() == q7p8!(const 1 / 2) && b.fract() == q7p8!(const -1 / 2)
+    );
+    test_assert!(
+        t,
+        c.floor() == q7p8!(const -3) && c.ceil() == q7p8!(const -2)
+    );
+    test_assert!(
+        t,
+        c.round() == q7p8!(const -3) && c.trunc() == q7p8!(const -2)
+    );
+    test_assert!(t, c.fract() == q7p8!(const -3 / 4));
+    test_assert!(t, a.to_int() == 2 && b.to_int() == -3 && c.to_int() == -3);
+    test_assert!(
+        t,
+        a.to_int_round() == 3 && b.to_int_round() == -3 && c.to_int_round() == -3
+    );
+    test_assert!(
+        t,
+        a.to_int_ceil() == 3 && b.to_int_ceil() == -2 && c.to_int_ceil() == -2
+    );
+    test_assert!(
+        t,
+        a.to_int_trunc() == 2 && b.to_int_trunc() == -2 && c.to_int_trunc() == -2
+    );
+
+    // Rounding up saturates at the edges.
+    test_assert!(t, max.ceil() == max && max.round() == max);
+    test_assert!(t, max.to_int_ceil() == 127 && max.to_int_round() == 127);
+    test_assert!(
+        t,
+        max.floor() == q7p8!(const 127) && max.to_int_trunc() == 127
+    );
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(
+        t,
+        min.floor() == min && min.ceil() == min && min.round() == min
+    );
+    test_assert!(t, min.trunc() == min && min.fract() == q7p8!(const 0));
+    test_assert!(t, min.to_int_round() == -128 && min.to_int_trunc() == -128);
+    const C: Q7p8 = q7p8!(const 3 / 4).round();
+    test_assert!(t, C == q7p8!(const 1));
+
+    // Compare all values to the reference.
+    let mut ok = true;
+    let sat = |v: i32| Q7p8::from_q(v.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+    let sat_int = |v: i32| (v >> 8).clamp(i8::MIN as i32, i8::MAX as i32) as i8;
+    for q in i16::MIN..=i16::MAX {
+        let a = Q7p8::from_q(q);
+        let q = q as i32;
+        let floor = q.div_euclid(256) * 256;
+        let ceil = -(-q).div_euclid(256) * 256;
+        let round = div_round_ref(q as i64, 256, false) as i32 * 256;
+        let trunc = q / 256 * 256;
+        ok &= a.floor() == sat(floor) && a.ceil() == sat(ceil);
+        ok &= a.round() == sat(round) && a.trunc() == sat(trunc);
+        ok &= a.fract() == sat(q - trunc);
+        ok &= a.to_int() == sat_int(floor) && a.to_int_ceil() == sat_int(ceil);
+        ok &= a.to_int_round() == sat_int(round) && a.to_int_trunc() == sat_int(trunc);
+    }
+    test_assert!(t, ok);
+}
+
+fn test_compare(t: &impl TestOps) {
+    t.begin("compare");
+
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+    let a = q7p8!(const 3 / 2);
+    let b = q7p8!(const -5 / 4);
+    test_assert!(
+        t,
+        a.min(b) == b && a.max(b) == a && b.min(a) == b && b.max(a) == a
+    );
+    test_assert!(
+        t,
+        a.clamp(q7p8!(const -1), q7p8!(const 1)) == q7p8!(const 1)
+    );
+    test_assert!(
+        t,
+        b.clamp(q7p8!(const -1), q7p8!(const 1)) == q7p8!(const -1)
+    );
+    test_assert!(t, b.clamp(min, max) == b);
+    test_assert!(
+        t,
+        a.signum() == q7p8!(const 1) && b.signum() == q7p8!(const -1)
+    );
+    test_assert!(t, q7p8!(const 0).signum() == q7p8!(const 0));
+    test_assert!(t, Q7p8::from_q(1).signum() == q7p8!(const 1));
+    test_assert!(t, !a.is_negative() && a.is_positive() && !a.is_zero());
+    test_assert!(t, b.is_negative() && !b.is_positive() && !b.is_zero());
+    let z = q7p8!(const 0);
+    test_assert!(t, !z.is_negative() && !z.is_positive() && z.is_zero());
+    test_assert!(
+        t,
+        a.copysign(b) == -a && b.copysign(a) == -b && b.copysign(b) == b
+    );
+    test_assert!(t, b.copysign(z) == -b && z.copysign(b) == z);
+    test_assert!(t, min.copysign(a) == max && min.copysign(b) == min);
+    test_assert!(t, a.abs_diff(b) == crate::uq8p8!(const 11 / 4));
+    test_assert!(t, min.abs_diff(max) == crate::UQ8p8::from_q(u16::MAX));
+
+    // The helpers are usable in const context.
+    const TABLE: [Q7p8; 3] = [
+        q7p8!(const 5).clamp(q7p8!(const 0), q7p8!(const 2)),
+        q7p8!(const -5).copysign(q7p8!(const 1)).min(q7p8!(const 4)),
+        q7p8!(const -3).signum(),
+    ];
+    test_assert!(t, TABLE[0] == q7p8!(const 2));
+    test_assert!(t, TABLE[1] == q7p8!(const 4));
+    test_assert!(t, TABLE[2] == q7p8!(const -1));
+
+    // Compare random values to the integer reference.
+    let mut ok = true;
+    let mut x: u32 = 0x1234_5678;
+    for _ in 0..4000 {
+        x = x.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let (qa, qb) = ((x >> 16) as i16, x as i16);
+        let (a, b) = (Q7p8::from_q(qa), Q7p8::from_q(qb));
+        ok &= a.min(b) == Ord::min(a, b) && a.max(b) == Ord::max(a, b);
+        let (lo, hi) = (a.min(b), a.max(b));
+        ok &= q7p8!(const 1).clamp(lo, hi) == Ord::clamp(q7p8!(const 1), lo, hi);
+        ok &= a.signum().to_q() == qa.signum() << 8;
+        ok &= a.is_negative() == qa.is_negative() && a.is_positive() == qa.is_positive();
+        let mag = if qb < 0 { -a.abs() } else { a.abs() };
+        ok &= a.copysign(b) == mag;
+        ok &= a.abs_diff(b).to_q() == qa.abs_diff(qb);
+    }
+    test_assert!(t, ok);
 }
 
 pub fn test_q7p8(t: &impl TestOps) {
@@ -87,8 +1091,31 @@ pub fn test_q7p8(t: &impl TestOps) {
     test_sub(t);
     test_mul(t);
     test_div(t);
+    test_div_zero(t);
     test_neg(t);
     test_abs(t);
+    test_checked(t);
+    test_wrapping(t);
+    test_overflowing(t);
+    test_round(t);
+    test_mul_add(t);
+    test_int(t);
+    test_widening_mul(t);
+    test_rounding(t);
+    test_compare(t);
+}
+
+pub fn test_q7p8_trig(t: &impl TestOps) {
+    t.print("q7p8 trig\n");
+    test_sqrt(t);
+    test_trig(t);
+    test_inv_trig(t);
+}
+
+pub fn test_q7p8_exp(t: &impl TestOps) {
+    t.print("q7p8 exp\n");
+    test_exp(t);
+    test_powi(t);
 }
 
 // vim: ts=4 sw=4 expandtab
@@ -0,0 +1,160 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, test_assert};
+use crate::{Q7p8, Q15p8, Reciprocal, q7p8, q15p8, recip::recip_mant};
+use avr_int24::I24;
+
+fn test_mant(t: &impl TestOps) {
+    t.begin("mant");
+
+    let mut ok = true;
+    for xn in 0x8000..=0xFFFF_u16 {
+        ok &= recip_mant(xn) == (1_u32 << 31) / xn as u32;
+    }
+    test_assert!(t, ok);
+}
+
+fn test_exact(t: &impl TestOps) {
+    t.begin("exact");
+
+    test_assert!(t, q7p8!(const 4).recip() == q7p8!(const 1 / 4));
+    test_assert!(t, q7p8!(const -1 / 8).recip() == q7p8!(const -8));
+    test_assert!(t, q7p8!(const 3).recip() == Q7p8::from_q(85));
+    test_assert!(t, q15p8!(const 10000).recip() == q15p8!(const 0));
+    test_assert!(
+        t,
+        q15p8!(const 256).recip() == Q15p8::from_q(I24::from_i32(1))
+    );
+    test_assert!(t, q15p8!(const -1 / 200).recip() == q15p8!(const -256));
+
+    // Zero and tiny values saturate.
+    let max = Q7p8::from_q(i16::MAX);
+    let min = Q7p8::from_q(i16::MIN);
+    test_assert!(t, q7p8!(const 0).recip() == max);
+    test_assert!(t, Q7p8::from_q(1).recip() == max);
+    test_assert!(t, Q7p8::from_q(-1).recip() == min);
+    test_assert!(t, Q7p8::from_q(2).recip() == max);
+    test_assert!(t, Q7p8::from_q(-2).recip() == min);
+    test_assert!(t, Q7p8::from_q(3).recip() == Q7p8::from_q(21845));
+    test_assert!(
+        t,
+        q15p8!(const 0).recip() == Q15p8::from_q(I24::from_i32(0x7F_FFFF))
+    );
+
+    // The reciprocal is identical to the full division.
+    let mut ok = true;
+    for q in i16::MIN..=i16::MAX {
+        let a = Q7p8::from_q(q);
+        ok &= a.recip() == q7p8!(const 1) / a;
+    }
+    let mut q: i32 = -0x80_0000;
+    while q <= 0x7F_FFFF {
+        let a = Q15p8::from_q(I24::from_i32(q));
+        ok &= a.recip() == q15p8!(const 1) / a;
+        // Scan small values densely and large ones sparsely.
+        q += if (-0x2_0000..0x2_0000).contains(&q) {
+            3
+        } else {
+            0x1F3
+        };
+    }
+    test_assert!(t, ok);
+}
+
+fn test_reciprocal(t: &impl TestOps) {
+    t.begin("reciprocal");
+
+    let r = Reciprocal::from_q7p8(q7p8!(const -5));
+    test_assert!(t, q7p8!(const 10) / r == q7p8!(const -2));
+    test_assert!(t, q7p8!(const -10) / r == q7p8!(const 2));
+    test_assert!(t, q7p8!(const 100) / r == q7p8!(const -20));
+    test_assert!(t, q15p8!(const 3000) / r == q15p8!(const -600));
+    // Quotients outside of the Q7.8 range have a relative error.
+    let q = (q15p8!(const 30000) / r).to_q().to_i32();
+    test_assert!(
+        t,
+        q <= -6000 * 256 && q > -6000 * 256 - 6000 * 256 / 0x4000 - 1
+    );
+    let r: Reciprocal = q15p8!(const 1 / 4).into();
+    test_assert!(t, q7p8!(const 100) / r == Q7p8::from_q(i16::MAX));
+    test_assert!(t, q15p8!(const 1000) / r == q15p8!(const 4000));
+    let mut a = q15p8!(const -1000);
+    a /= r;
+    test_assert!(t, a == q15p8!(const -4000));
+    let mut a = q7p8!(const 3);
+    a /= Reciprocal::from(q7p8!(const 3));
+    test_assert!(t, a == q7p8!(const 1));
+
+    // Division by zero saturates according to the sign of the dividend.
+    let r = Reciprocal::from_q7p8(q7p8!(const 0));
+    test_assert!(t, q7p8!(const 1) / r == Q7p8::from_q(i16::MAX));
+    test_assert!(t, q7p8!(const 0) / r == Q7p8::from_q(i16::MAX));
+    test_assert!(t, q7p8!(const -1) / r == Q7p8::from_q(i16::MIN));
+    test_assert!(
+        t,
+        q15p8!(const -1) / r == Q15p8::from_q(I24::from_i32(-0x80_0000))
+    );
+
+    // Exact quotients are calculated exactly.
+    let mut ok = true;
+    for d in (-0x7FFF_i32..=0x7FFF).step_by(0x1F).filter(|&d| d != 0) {
+        let r = Reciprocal::from_q7p8(Q7p8::from_q(d as i16));
+        for j in -127..=127 {
+            // (j * d) / d == j
+            if let Ok(a) = i16::try_from(j * d) {
+                ok &= (Q7p8::from_q(a) / r).to_q() as i32 == j * 256;
+            }
+        }
+    }
+    test_assert!(t, ok);
+
+    // Check the error bound against the exact quotient.
+    let mut ok = true;
+    let mut d: i32 = -0x80_0000;
+    while d <= 0x7F_FFFF {
+        let r = Reciprocal::from_q15p8(Q15p8::from_q(I24::from_i32(d)));
+        let mut a: i32 = -0x80_0000;
+        while a <= 0x7F_FFFF {
+            if d != 0 {
+                let exact = ((a as i64) << 8) / d as i64;
+                let q = (Q15p8::from_q(I24::from_i32(a)) / r).to_q().to_i32() as i64;
+                let e = exact.clamp(-0x80_0000, 0x7F_FFFF);
+                // The magnitude is never too small.
+                ok &= (q - e).abs() <= 1 + (e.abs() >> 14) && q.abs() >= e.abs();
+                if (-0x1_0000..0x1_0000).contains(&d) && (-0x8000..0x8000).contains(&e) {
+                    ok &= (q - e).abs() <= 1;
+                }
+                if (-0x8000..0x8000).contains(&a) {
+                    let q = (Q7p8::from_q(a as i16) / r).to_q() as i64;
+                    let e = exact.clamp(i16::MIN as i64, i16::MAX as i64);
+                    ok &= (q - e).abs() <= 1 + (e.abs() >> 14) && q.abs() >= e.abs();
+                    if (-0x1_0000..0x1_0000).contains(&d) {
+                        ok &= (q - e).abs() <= 1;
+                    }
+                }
+            }
+            a += if (-0x1_0000..0x1_0000).contains(&a) {
+                0x3F1
+            } else {
+                0x3_F3F1
+            };
+        }
+        d += if (-0x1000..0x1000).contains(&d) {
+            7
+        } else {
+            0x1_F3F1
+        };
+    }
+    test_assert!(t, ok);
+}
+
+pub fn test_recip(t: &impl TestOps) {
+    t.print("recip\n");
+    test_mant(t);
+    test_exact(t);
+    test_reciprocal(t);
+}
+
+// vim: ts=4 sw=4 expandtab
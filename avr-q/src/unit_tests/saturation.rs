@@ -0,0 +1,311 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, test_assert};
+use crate::{
+    Q, Q0p7, Q0p15, Q3p4, Q7p8, Q15p8, Q15p16, SaturationFlag, UQ8p8, UQ16p8, q7p8, q15p8, q15p16,
+    q23p8, uq8p8, uq16p8,
+};
+
+fn test_flag(t: &impl TestOps) {
+    t.begin("flag");
+
+    SaturationFlag::clear();
+    test_assert!(t, !SaturationFlag::get());
+    test_assert!(t, !SaturationFlag::take());
+
+    let _ = q7p8!(const 1) + q7p8!(const 2);
+    test_assert!(t, !SaturationFlag::get());
+    let _ = q7p8!(const 100) + q7p8!(const 100);
+    test_assert!(t, SaturationFlag::get());
+    let _ = q7p8!(const 1) + q7p8!(const 2);
+    test_assert!(t, SaturationFlag::get());
+    test_assert!(t, SaturationFlag::take());
+    test_assert!(t, !SaturationFlag::get());
+    test_assert!(t, !SaturationFlag::take());
+}
+
+fn test_q7p8(t: &impl TestOps) {
+    t.begin("q7p8");
+
+    let a = q7p8!(const 100);
+    let b = q7p8!(const 2);
+
+    SaturationFlag::clear();
+    let _ = a - b;
+    let _ = a * q7p8!(const -1 / 2);
+    let _ = a / b;
+    let _ = -a;
+    let _ = a.checked_mul(a);
+    let _ = a.overflowing_mul(a);
+    let _ = a.wrapping_mul(a);
+    test_assert!(t, !SaturationFlag::take());
+
+    let _ = -a - a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * b;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / q7p8!(const 1 / 2);
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / q7p8!(const 0);
+    test_assert!(t, SaturationFlag::take());
+    let _ = -crate::Q7p8::from_q(i16::MIN);
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * 2_i8;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / 0_i16;
+    test_assert!(t, SaturationFlag::take());
+    let _ = 200_i16 / q7p8!(const 1);
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * -1_i8 / 3_i16;
+    let _ = 2_i16 / a;
+    test_assert!(t, !SaturationFlag::take());
+    let _ = q7p8!(const 0).recip();
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / crate::Reciprocal::from_q7p8(q7p8!(const 1 / 2));
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.recip();
+    let _ = a / crate::Reciprocal::from_q7p8(b);
+    test_assert!(t, !SaturationFlag::take());
+    let _ = (-a).sqrt();
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.sqrt();
+    let _ = (-a).checked_sqrt();
+    test_assert!(t, !SaturationFlag::take());
+    let _ = a.asin();
+    test_assert!(t, SaturationFlag::take());
+    let _ = (-a).acos();
+    test_assert!(t, SaturationFlag::take());
+    let _ = q7p8!(const 1).asin();
+    let _ = a.atan2(-a);
+    let _ = a.const_asin();
+    test_assert!(t, !SaturationFlag::take());
+    let _ = q7p8!(const 5).exp();
+    test_assert!(t, SaturationFlag::take());
+    let _ = q7p8!(const 0).ln();
+    test_assert!(t, SaturationFlag::take());
+    let _ = q7p8!(const -2).pow(b);
+    test_assert!(t, SaturationFlag::take());
+    let _ = q7p8!(const 4).exp();
+    let _ = q7p8!(const -20).exp2();
+    let _ = a.log2();
+    let _ = b.pow(q7p8!(const 6));
+    test_assert!(t, !SaturationFlag::take());
+    let _ = q7p8!(const 2).powi(7);
+    test_assert!(t, SaturationFlag::take());
+    let _ = q7p8!(const 12).square();
+    test_assert!(t, SaturationFlag::take());
+    let _ = q7p8!(const 2).powi(6);
+    let _ = q7p8!(const 1 / 2).powi(255);
+    let _ = q7p8!(const -5).cube();
+    test_assert!(t, !SaturationFlag::take());
+    let min = crate::Q7p8::from_q(i16::MIN);
+    let _ = a + a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.add(a).sub(min);
+    let _ = min.neg().abs();
+    test_assert!(t, !SaturationFlag::take());
+}
+
+fn test_q15p8(t: &impl TestOps) {
+    t.begin("q15p8");
+
+    let a = q15p8!(const 30000);
+    let b = q15p8!(const 2);
+
+    SaturationFlag::clear();
+    let _ = a + b;
+    let _ = a - b;
+    let _ = a * q15p8!(const -1 / 2);
+    let _ = a / b;
+    let _ = -a;
+    let _ = a.abs();
+    let _ = a.checked_mul(a);
+    let _ = a.overflowing_mul(a);
+    let _ = a.wrapping_mul(a);
+    test_assert!(t, !SaturationFlag::take());
+
+    let _ = a + a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = -a - a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * b;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / q15p8!(const 1 / 2);
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / q15p8!(const 0);
+    test_assert!(t, SaturationFlag::take());
+    let min = q15p8!(const -32768);
+    let _ = -min;
+    test_assert!(t, SaturationFlag::take());
+    let _ = min.abs();
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * 2_i16;
+    test_assert!(t, SaturationFlag::take());
+    let _ = min / -1_i8;
+    test_assert!(t, SaturationFlag::take());
+    let _ = 30000_i16 / b;
+    let _ = a * -1_i8 / 3_i16;
+    test_assert!(t, !SaturationFlag::take());
+    let _ = 1_i16 / q15p8!(const 0);
+    test_assert!(t, SaturationFlag::take());
+    let _ = (-a).sqrt();
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.sqrt();
+    test_assert!(t, !SaturationFlag::take());
+    let _ = q15p8!(const 15).exp2();
+    test_assert!(t, SaturationFlag::take());
+    let _ = (-a).log2();
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.pow(b);
+    test_assert!(t, SaturationFlag::take());
+    let _ = q15p8!(const 14).exp2();
+    let _ = a.ln();
+    test_assert!(t, !SaturationFlag::take());
+    let _ = q15p8!(const -33).cube();
+    test_assert!(t, SaturationFlag::take());
+    let _ = q15p8!(const 2).powi(14);
+    let _ = q15p8!(const 181).square();
+    test_assert!(t, !SaturationFlag::take());
+}
+
+fn test_q(t: &impl TestOps) {
+    t.begin("q");
+
+    type Q3p12 = Q<i16, 12>;
+    let a = Q3p12::from_int(6);
+    let b = Q3p12::from_int(2);
+
+    SaturationFlag::clear();
+    let _ = a + (-b);
+    let _ = a * Q3p12::from_fraction(1, 2);
+    let _ = a / b;
+    let _ = a.mul_round(Q3p12::from_fraction(1, 3));
+    let _ = a.checked_mul(a);
+    let _ = a.overflowing_div(Q3p12::from_int(0));
+    test_assert!(t, !SaturationFlag::take());
+
+    let _ = a + b;
+    test_assert!(t, SaturationFlag::take());
+    let _ = -a - a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * b;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.div_round(Q3p12::from_fraction(1, 2));
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / Q3p12::from_int(0);
+    test_assert!(t, SaturationFlag::take());
+    let _ = -Q3p12::from_int(-8);
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q3p12::from_int(-8).abs();
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.add(b);
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.const_add(b).const_sub(-a);
+    let _ = Q3p12::from_int(-8).const_neg().const_abs();
+    test_assert!(t, !SaturationFlag::take());
+
+    // The Q7.8 format uses the Q7p8 implementation.
+    let a = Q::<i16, 8>::from_int(100);
+    let _ = a * a;
+    test_assert!(t, SaturationFlag::take());
+}
+
+fn test_unsigned(t: &impl TestOps) {
+    t.begin("unsigned");
+
+    let a = uq8p8!(const 200);
+    let b = uq8p8!(const 2);
+
+    SaturationFlag::clear();
+    let _ = a - b;
+    let _ = a * uq8p8!(const 1 / 2);
+    let _ = a / b;
+    let _ = a.checked_sub(a + b);
+    let _ = a.overflowing_mul(a);
+    test_assert!(t, !SaturationFlag::take());
+
+    let _ = a + a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = b - a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a * b;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a / uq8p8!(const 0);
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.add(a).sub(b).sub(a).sub(a);
+    test_assert!(t, !SaturationFlag::take());
+
+    let a = uq16p8!(const 40000);
+    let b = uq16p8!(const 2);
+    let _ = a - b;
+    let _ = a / b;
+    test_assert!(t, !SaturationFlag::take());
+    let _ = b - a;
+    test_assert!(t, SaturationFlag::take());
+    let _ = b.sub(a);
+    test_assert!(t, !SaturationFlag::take());
+    let _ = a * b;
+    test_assert!(t, SaturationFlag::take());
+    let _ = a.div_round(uq16p8!(const 1 / 2));
+    test_assert!(t, SaturationFlag::take());
+}
+
+fn test_convert(t: &impl TestOps) {
+    t.begin("convert");
+
+    SaturationFlag::clear();
+    let _ = Q7p8::from(q15p8!(const -128));
+    let _ = Q7p8::from(q15p16!(const 32767 / 256));
+    let _ = Q7p8::from(uq8p8!(const 127));
+    let _ = Q15p8::from(q23p8!(const -32768));
+    let _ = Q15p16::from(q23p8!(const 32767));
+    let _ = Q0p15::from(q7p8!(const -1));
+    let _ = Q3p4::from(q15p8!(const 7));
+    let _ = Q0p7::from(q7p8!(const 1 / 2));
+    let _ = UQ8p8::from(uq16p8!(const 255));
+    let _ = UQ16p8::from(q15p8!(const 0));
+    test_assert!(t, !SaturationFlag::take());
+
+    let _ = Q7p8::from(q15p8!(const 128));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q7p8::from(q15p16!(const -129));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q7p8::from(uq16p8!(const 128));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q15p8::from(uq16p8!(const 32768));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q15p8::from(q23p8!(const -32769));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q15p16::from(q23p8!(const 32768));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q0p15::from(q15p8!(const 1));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q3p4::from(q7p8!(const -9));
+    test_assert!(t, SaturationFlag::take());
+    let _ = Q0p7::from(q15p8!(const -2));
+    test_assert!(t, SaturationFlag::take());
+    let _ = UQ8p8::from(q7p8!(const -1 / 2));
+    test_assert!(t, SaturationFlag::take());
+    let _ = UQ8p8::from(q15p8!(const 256));
+    test_assert!(t, SaturationFlag::take());
+    let _ = UQ16p8::from(q7p8!(const -1));
+    test_assert!(t, SaturationFlag::take());
+
+    let _ = q15p8!(const 128).to_q7p8();
+    let _ = q7p8!(const -1).to_uq8p8();
+    test_assert!(t, !SaturationFlag::take());
+}
+
+pub fn test_saturation(t: &impl TestOps) {
+    t.print("saturation\n");
+    test_flag(t);
+    test_q7p8(t);
+    test_q15p8(t);
+    test_q(t);
+    test_unsigned(t);
+    test_convert(t);
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,210 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use super::{TestOps, div_round_ref, test_assert};
+use crate::{Q7p8, Q15p8, UQ8p8, UQ16p8, Wrapping, q7p8, q15p8, uq8p8, uq16p8};
+use avr_int24::I24;
+
+fn test_base(t: &impl TestOps) {
+    t.begin("base");
+
+    let a = uq8p8!(const 10 / 2).to_q();
+    let b = uq8p8!(10 / 2).to_q();
+    let c = uq8p8!(const 5).to_q();
+    let d = uq8p8!(5).to_q();
+    test_assert!(t, a == 0x0500 && a == b && b == c && c == d);
+
+    let a = uq8p8!(const 3 / 2).to_q();
+    test_assert!(t, a == 0x0180);
+
+    // The whole 8 bit integer range is available.
+    let a = uq8p8!(const 255);
+    test_assert!(t, a.to_q() == 0xFF00 && a.to_int() == 255);
+
+    let a: UQ8p8 = 200_u8.into();
+    let b: u8 = a.into();
+    test_assert!(t, a.to_q() == 0xC800 && b == 200);
+}
+
+fn test_add_sub(t: &impl TestOps) {
+    t.begin("add_sub");
+
+    let a = uq8p8!(const 7 / 3);
+    let b = uq8p8!(const 1 / 3);
+    test_assert!(t, a + b == uq8p8!(const 8 / 3));
+    test_assert!(t, a - b == uq8p8!(const 6 / 3));
+
+    let max = UQ8p8::from_q(u16::MAX);
+    let zero = uq8p8!(const 0);
+    test_assert!(t, uq8p8!(const 200) + uq8p8!(const 100) == max);
+    test_assert!(t, b - a == zero);
+    test_assert!(t, b.sub(a) == zero);
+    test_assert!(t, b.overflowing_sub(a) == (zero, true));
+    test_assert!(t, b.checked_sub(a).is_none());
+    test_assert!(t, a.checked_sub(b) == Some(uq8p8!(const 2)));
+    test_assert!(t, max.overflowing_add(b) == (max, true));
+    test_assert!(t, max.checked_add(b).is_none());
+
+    let mut c = a;
+    c += b;
+    c -= uq8p8!(const 1);
+    test_assert!(t, c == uq8p8!(const 5 / 3));
+}
+
+fn test_mul_div(t: &impl TestOps) {
+    t.begin("mul_div");
+
+    let a = uq8p8!(const 3 / 2);
+    let b = uq8p8!(const 100);
+    test_assert!(t, a * b == uq8p8!(const 150));
+    test_assert!(t, a.const_mul(b) == uq8p8!(const 150));
+    test_assert!(t, b / a == uq8p8!(const 200 / 3));
+    test_assert!(t, b.const_div(a) == uq8p8!(const 200 / 3));
+
+    // 150 * 2 doesn't fit.
+    let max = UQ8p8::from_q(u16::MAX);
+    let c = uq8p8!(const 150);
+    test_assert!(t, c * uq8p8!(const 2) == max);
+    test_assert!(t, c.overflowing_mul(uq8p8!(const 2)) == (max, true));
+    test_assert!(t, c.checked_mul(uq8p8!(const 2)).is_none());
+    test_assert!(t, c / uq8p8!(const 1 / 2) == max);
+    test_assert!(t, c.checked_div(uq8p8!(const 1 / 2)).is_none());
+
+    // Division by zero saturates to the largest value.
+    let zero = uq8p8!(const 0);
+    test_assert!(t, c / zero == max && zero / uq8p8!(const 0) == max);
+    test_assert!(t, c.const_div(zero) == max);
+    test_assert!(t, c.overflowing_div(zero) == (max, true));
+    test_assert!(t, c.checked_div(zero).is_none());
+    test_assert!(t, c.div_round(zero) == max);
+    test_assert!(t, UQ8p8::from_fraction(1, 0) == max);
+
+    // 3/256 * 1/2 is a tie.
+    let d = UQ8p8::from_q(3);
+    let h = uq8p8!(const 1 / 2);
+    test_assert!(t, d * h == UQ8p8::from_q(1));
+    test_assert!(t, d.mul_round(h) == UQ8p8::from_q(2));
+    test_assert!(t, d.mul_round_even(h) == UQ8p8::from_q(2));
+    test_assert!(t, UQ8p8::from_q(5).mul_round_even(h) == UQ8p8::from_q(2));
+    test_assert!(
+        t,
+        UQ8p8::from_q(3).div_round(uq8p8!(const 2)) == UQ8p8::from_q(2)
+    );
+    test_assert!(
+        t,
+        UQ8p8::from_q(5).div_round_even(uq8p8!(const 2)) == UQ8p8::from_q(2)
+    );
+
+    // Compare all variants to the reference.
+    let mut ok = true;
+    for i in 0..40_u32 {
+        for j in 0..40_u32 {
+            let a = (i * 1681).min(0xFFFF) as u16;
+            let b = 0xFFFF_u32.saturating_sub(j * 1733).max(1) as u16;
+            let (qa, qb) = (UQ8p8::from_q(a), UQ8p8::from_q(b));
+            let q = |v: i64| UQ8p8::from_q(v.min(0xFFFF) as u16);
+            let sat = |v: i64| v > 0xFFFF;
+
+            let p = a as i64 * b as i64;
+            ok &= qa.overflowing_mul(qb) == (q(p >> 8), sat(p >> 8));
+            ok &= qa * qb == qa.const_mul(qb);
+            ok &= qa.wrapping_mul(qb) == UQ8p8::from_q((p >> 8) as u16);
+            let c = q(div_round_ref(p, 0x100, false));
+            ok &= qa.mul_round(qb) == c && qa.const_mul_round(qb) == c;
+            let c = q(div_round_ref(p, 0x100, true));
+            ok &= qa.mul_round_even(qb) == c && qa.const_mul_round_even(qb) == c;
+
+            let n = (a as i64) << 8;
+            ok &= qa.overflowing_div(qb) == (q(n / b as i64), sat(n / b as i64));
+            ok &= qa / qb == qa.const_div(qb);
+            let c = q(div_round_ref(n, b as i64, false));
+            ok &= qa.div_round(qb) == c && qa.const_div_round(qb) == c;
+            let c = q(div_round_ref(n, b as i64, true));
+            ok &= qa.div_round_even(qb) == c && qa.const_div_round_even(qb) == c;
+        }
+    }
+    test_assert!(t, ok);
+}
+
+fn test_wrapping(t: &impl TestOps) {
+    t.begin("wrapping");
+
+    let a = uq8p8!(const 250);
+    let b = uq8p8!(const 10);
+    test_assert!(t, a.wrapping_add(b) == uq8p8!(const 4));
+    test_assert!(t, b.wrapping_sub(a) == uq8p8!(const 16));
+    test_assert!(t, a.wrapping_mul(b) == uq8p8!(const 196));
+    test_assert!(t, uq8p8!(const 1).wrapping_neg() == uq8p8!(const 255));
+    test_assert!(t, Wrapping(a) + Wrapping(b) == Wrapping(uq8p8!(const 4)));
+    test_assert!(t, Wrapping(b) - Wrapping(a) == Wrapping(uq8p8!(const 16)));
+    test_assert!(t, a.wrapping_div(uq8p8!(const 1 / 2)) == uq8p8!(const 244));
+    test_assert!(t, a.wrapping_div(b) == uq8p8!(const 25));
+    test_assert!(
+        t,
+        a.wrapping_div(uq8p8!(const 0)) == UQ8p8::from_q(u16::MAX)
+    );
+    test_assert!(
+        t,
+        Wrapping(a) / Wrapping(uq8p8!(const 1 / 2)) == Wrapping(uq8p8!(const 244))
+    );
+    let mut d = Wrapping(a);
+    d /= Wrapping(b);
+    test_assert!(t, d == Wrapping(uq8p8!(const 25)));
+}
+
+fn test_conv(t: &impl TestOps) {
+    t.begin("conv");
+
+    let a = uq8p8!(const 3 / 4);
+    test_assert!(t, a.to_q7p8() == q7p8!(const 3 / 4));
+    test_assert!(t, a.to_q15p8() == q15p8!(const 3 / 4));
+    test_assert!(t, a.to_uq16p8() == uq16p8!(const 3 / 4));
+    test_assert!(t, q7p8!(const 3 / 4).to_uq8p8() == a);
+    test_assert!(t, q15p8!(const 3 / 4).to_uq8p8() == a);
+
+    // The conversions saturate at zero and at the largest value.
+    let max = UQ8p8::from_q(u16::MAX);
+    test_assert!(t, uq8p8!(const 200).to_q7p8() == Q7p8::from_q(i16::MAX));
+    test_assert!(t, uq8p8!(const 200).to_q15p8() == q15p8!(const 200));
+    test_assert!(t, q7p8!(const -1 / 2).to_uq8p8() == uq8p8!(const 0));
+    test_assert!(t, Q7p8::from_q(i16::MIN).to_uq8p8() == uq8p8!(const 0));
+    test_assert!(t, q15p8!(const -1000).to_uq8p8() == uq8p8!(const 0));
+    test_assert!(t, q15p8!(const 1000).to_uq8p8() == max);
+    test_assert!(t, q15p8!(const 256).to_uq8p8() == max);
+    test_assert!(t, Q15p8::from_q(I24::from_i32(0xFFFF)).to_uq8p8() == max);
+
+    let b: UQ8p8 = q7p8!(const 5).into();
+    let c: UQ8p8 = q15p8!(const 7).into();
+    let d: UQ8p8 = uq16p8!(const 9).into();
+    test_assert!(
+        t,
+        b == uq8p8!(const 5) && c == uq8p8!(const 7) && d == uq8p8!(const 9)
+    );
+    let b: Q7p8 = b.into();
+    let c: Q15p8 = c.into();
+    let d: UQ16p8 = d.into();
+    test_assert!(
+        t,
+        b == q7p8!(const 5) && c == q15p8!(const 7) && d == uq16p8!(const 9)
+    );
+}
+
+fn test_cmp(t: &impl TestOps) {
+    t.begin("cmp");
+
+    test_assert!(t, uq8p8!(const 200) > uq8p8!(const 100));
+    test_assert!(t, uq8p8!(const 1 / 2) < uq8p8!(const 1));
+}
+
+pub fn test_uq8p8(t: &impl TestOps) {
+    t.print("uq8p8\n");
+    test_base(t);
+    test_add_sub(t);
+    test_mul_div(t);
+    test_wrapping(t);
+    test_conv(t);
+    test_cmp(t);
+}
+
+// vim: ts=4 sw=4 expandtab
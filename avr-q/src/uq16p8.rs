@@ -0,0 +1,590 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{round::Round, saturation::saturating};
+
+/// UQ16.8 unsigned fixed point number.
+///
+/// The raw value is stored in 24 bits.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct UQ16p8([u8; 3]);
+
+/// Construct a UQ16.8 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the UQ16.8 value and the fractional part is zero.
+///
+/// `uq16p8!(42)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `uq16p8!(42 / 10)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `uq16p8!(const 42 / 10)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! uq16p8 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::UQ16p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::UQ16p8::const_from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::UQ16p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::UQ16p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::UQ16p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::UQ16p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::UQ16p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::UQ16p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::UQ16p8::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::UQ16p8::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::UQ16p8::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::UQ16p8::from_int($numerator)
+    };
+}
+
+/// Largest raw 24 bit value.
+const MAX24: u32 = 0xFF_FFFF;
+
+/// Convert a signed raw Q.8 value to [UQ16p8].
+/// Negative values saturate at zero.
+/// Returns the saturated value and `true`, if saturation happened.
+pub(crate) const fn uq16p8_from_q8(q: i32) -> (UQ16p8, bool) {
+    if q < 0 {
+        (UQ16p8::from_q(0), true)
+    } else {
+        (UQ16p8::from_q(q as u32), false)
+    }
+}
+
+/// Convert and saturate a wide raw value to a raw UQ16.8 value.
+/// Returns the saturated value and `true`, if saturation happened.
+#[inline(always)]
+const fn overflowing_from_u32(v: u32) -> (u32, bool) {
+    if v > MAX24 { (MAX24, true) } else { (v, false) }
+}
+
+/// Multiply two raw UQ16.8 values.
+/// The product is rounded according to `round`.
+/// Returns the saturated product and `true`, if saturation happened.
+///
+/// The full 48 bit product is never materialized.
+/// Instead the values are split into 8 bit limbs,
+/// so that only 16x16 bit multiplications are needed.
+#[inline(always)]
+const fn mul_uq16p8(a: u32, b: u32, round: Round) -> (u32, bool) {
+    let (ah, al) = (a >> 8, a & 0xFF);
+    let (bh, bl) = (b >> 8, b & 0xFF);
+
+    let hi = ah * bh;
+    if hi > 0xFFFF {
+        // The product is out of range for 24 bit.
+        return (MAX24, true);
+    }
+    let mid = (ah * bl) + (al * bh);
+    let lo = al * bl;
+
+    let c = (hi << 8) + mid + (lo >> 8);
+    let c = c + round.round_up(false, c & 1 != 0, lo & 0xFF, 0x100) as u32;
+    overflowing_from_u32(c)
+}
+
+/// Divide two raw UQ16.8 values.
+/// The quotient is rounded according to `round`.
+/// Returns the saturated quotient and `true`, if saturation happened.
+#[inline(always)]
+const fn div_uq16p8(a: u32, b: u32, round: Round) -> (u32, bool) {
+    if b == 0 {
+        return (MAX24, true);
+    }
+    // The shifted 24 bit dividend fits into 32 bits.
+    let n = a << 8;
+    let q = n / b;
+    let rem = n % b;
+    let q = q + round.round_up(false, q & 1 != 0, rem, b) as u32;
+    overflowing_from_u32(q)
+}
+
+#[allow(clippy::should_implement_trait)]
+impl UQ16p8 {
+    /// Length of the fractional part, in bits.
+    pub const SHIFT: usize = 8;
+
+    /// Convert a raw UQ16.8 value to [UQ16p8].
+    /// The upper 8 bits of the raw value are discarded.
+    pub const fn from_q(q: u32) -> Self {
+        let q = q.to_le_bytes();
+        Self([q[0], q[1], q[2]])
+    }
+
+    /// Convert an integer value to [UQ16p8] with fractional part being zero.
+    pub const fn from_int(int: u16) -> Self {
+        Self::from_q((int as u32) << Self::SHIFT)
+    }
+
+    /// Convert a numerator/denominator fraction to [UQ16p8].
+    pub fn from_fraction(numerator: u16, denominator: u16) -> Self {
+        Self::from_q(numerator as u32) / Self::from_q(denominator as u32)
+    }
+
+    /// Convert a numerator/denominator fraction to [UQ16p8].
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::from_fraction] instead.
+    pub const fn const_from_fraction(numerator: u16, denominator: u16) -> Self {
+        Self::from_q(numerator as u32).const_div(Self::from_q(denominator as u32))
+    }
+
+    /// Convert this [UQ16p8] to a raw UQ16.8 value.
+    pub const fn to_q(self) -> u32 {
+        u32::from_le_bytes([self.0[0], self.0[1], self.0[2], 0])
+    }
+
+    /// Extract the integer part out of this [UQ16p8].
+    pub const fn to_int(self) -> u16 {
+        (self.to_q() >> Self::SHIFT) as u16
+    }
+
+    /// Convert this [UQ16p8] to a [crate::Q7p8].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::const_overflowing_from_i32(self.to_q() as i32).0
+    }
+
+    /// Convert this [UQ16p8] to a [crate::Q15p8].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::const_overflowing_from_i32(self.to_q() as i32).0
+    }
+
+    /// Convert this [UQ16p8] to a [crate::UQ8p8].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_uq8p8(&self) -> crate::UQ8p8 {
+        crate::uq8p8::uq8p8_from_q8(self.to_q() as i32).0
+    }
+
+    /// Add and saturate two [UQ16p8] values.
+    #[inline(never)]
+    pub const fn add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Subtract and saturate two [UQ16p8] values.
+    /// The difference saturates at zero.
+    #[inline(never)]
+    pub const fn sub(self, other: Self) -> Self {
+        Self::from_q(self.to_q().saturating_sub(other.to_q()))
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    #[inline(never)]
+    pub fn mul(self, other: Self) -> Self {
+        Self::from_q(saturating!(
+            mul_uq16p8(self.to_q(), other.to_q(), Round::Floor),
+            mul_uq16p8(self.to_q(), other.to_q(), Round::Floor).0
+        ))
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::mul] instead.
+    pub const fn const_mul(self, other: Self) -> Self {
+        Self::from_q(mul_uq16p8(self.to_q(), other.to_q(), Round::Floor).0)
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div(self, other: Self) -> Self {
+        Self::from_q(saturating!(
+            div_uq16p8(self.to_q(), other.to_q(), Round::Floor),
+            div_uq16p8(self.to_q(), other.to_q(), Round::Floor).0
+        ))
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::div] instead.
+    pub const fn const_div(self, other: Self) -> Self {
+        Self::from_q(div_uq16p8(self.to_q(), other.to_q(), Round::Floor).0)
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    #[inline(never)]
+    pub fn mul_round(self, other: Self) -> Self {
+        Self::from_q(saturating!(
+            mul_uq16p8(self.to_q(), other.to_q(), Round::Nearest),
+            mul_uq16p8(self.to_q(), other.to_q(), Round::Nearest).0
+        ))
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::mul_round] instead.
+    pub const fn const_mul_round(self, other: Self) -> Self {
+        Self::from_q(mul_uq16p8(self.to_q(), other.to_q(), Round::Nearest).0)
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    #[inline(never)]
+    pub fn mul_round_even(self, other: Self) -> Self {
+        Self::from_q(saturating!(
+            mul_uq16p8(self.to_q(), other.to_q(), Round::NearestEven),
+            mul_uq16p8(self.to_q(), other.to_q(), Round::NearestEven).0
+        ))
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::mul_round_even] instead.
+    pub const fn const_mul_round_even(self, other: Self) -> Self {
+        Self::from_q(mul_uq16p8(self.to_q(), other.to_q(), Round::NearestEven).0)
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_round(self, other: Self) -> Self {
+        Self::from_q(saturating!(
+            div_uq16p8(self.to_q(), other.to_q(), Round::Nearest),
+            div_uq16p8(self.to_q(), other.to_q(), Round::Nearest).0
+        ))
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::div_round] instead.
+    pub const fn const_div_round(self, other: Self) -> Self {
+        Self::from_q(div_uq16p8(self.to_q(), other.to_q(), Round::Nearest).0)
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_round_even(self, other: Self) -> Self {
+        Self::from_q(saturating!(
+            div_uq16p8(self.to_q(), other.to_q(), Round::NearestEven),
+            div_uq16p8(self.to_q(), other.to_q(), Round::NearestEven).0
+        ))
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::div_round_even] instead.
+    pub const fn const_div_round_even(self, other: Self) -> Self {
+        Self::from_q(div_uq16p8(self.to_q(), other.to_q(), Round::NearestEven).0)
+    }
+
+    /// Add two [UQ16p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        Self::from_q(self.to_q() + other.to_q())
+    }
+
+    /// Subtract two [UQ16p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self::from_q(self.to_q().wrapping_sub(other.to_q()))
+    }
+
+    /// Multiply two [UQ16p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        // Only the lower 32 bits of the product are needed to get the wrapped result.
+        Self::from_q(self.to_q().wrapping_mul(other.to_q()) >> Self::SHIFT)
+    }
+
+    /// Divide two [UQ16p8] values.
+    /// The quotient is truncated towards zero and wraps around on overflow.
+    /// Division by zero saturates.
+    pub const fn wrapping_div(self, other: Self) -> Self {
+        if other.to_q() == 0 {
+            self.const_div(other)
+        } else {
+            // The shifted 24 bit dividend fits into 32 bits.
+            Self::from_q((self.to_q() << Self::SHIFT) / other.to_q())
+        }
+    }
+
+    /// Negate this [UQ16p8] value.
+    /// The result wraps around, unless the value is zero.
+    pub const fn wrapping_neg(self) -> Self {
+        Self::from_q(self.to_q().wrapping_neg())
+    }
+
+    /// Add two [UQ16p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Subtract two [UQ16p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Multiply two [UQ16p8] values.
+    /// Returns `None` on overflow.
+    #[inline(never)]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.const_checked_mul(other)
+    }
+
+    /// Multiply two [UQ16p8] values.
+    /// Returns `None` on overflow.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::checked_mul] instead.
+    pub const fn const_checked_mul(self, other: Self) -> Option<Self> {
+        match self.const_overflowing_mul(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Divide two [UQ16p8] values.
+    /// Returns `None` on overflow or division by zero.
+    #[inline(never)]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.const_checked_div(other)
+    }
+
+    /// Divide two [UQ16p8] values.
+    /// Returns `None` on overflow or division by zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::checked_div] instead.
+    pub const fn const_checked_div(self, other: Self) -> Option<Self> {
+        match self.const_overflowing_div(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Add and saturate two [UQ16p8] values.
+    /// Returns the saturated sum and `true`, if saturation happened.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (c, sat) = overflowing_from_u32(self.to_q() + other.to_q());
+        (Self::from_q(c), sat)
+    }
+
+    /// Subtract and saturate two [UQ16p8] values.
+    /// Returns the saturated difference and `true`, if saturation happened.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (c, sat) = self.to_q().overflowing_sub(other.to_q());
+        (Self::from_q(if sat { 0 } else { c }), sat)
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    #[inline(never)]
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        self.const_overflowing_mul(other)
+    }
+
+    /// Multiply and saturate two [UQ16p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::overflowing_mul] instead.
+    pub const fn const_overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = mul_uq16p8(self.to_q(), other.to_q(), Round::Floor);
+        (Self::from_q(c), sat)
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+        self.const_overflowing_div(other)
+    }
+
+    /// Divide and saturate two [UQ16p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ16p8::overflowing_div] instead.
+    pub const fn const_overflowing_div(self, other: Self) -> (Self, bool) {
+        let (c, sat) = div_uq16p8(self.to_q(), other.to_q(), Round::Floor);
+        (Self::from_q(c), sat)
+    }
+}
+
+impl From<u8> for UQ16p8 {
+    fn from(value: u8) -> Self {
+        Self::from_int(value.into())
+    }
+}
+
+impl From<u16> for UQ16p8 {
+    fn from(value: u16) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<UQ16p8> for u16 {
+    fn from(value: UQ16p8) -> Self {
+        value.to_int()
+    }
+}
+
+impl From<crate::Q7p8> for UQ16p8 {
+    fn from(v: crate::Q7p8) -> UQ16p8 {
+        saturating!(uq16p8_from_q8(v.to_q() as i32), v.to_uq16p8())
+    }
+}
+
+impl From<crate::Q15p8> for UQ16p8 {
+    fn from(v: crate::Q15p8) -> UQ16p8 {
+        saturating!(uq16p8_from_q8(v.to_q().to_i32()), v.to_uq16p8())
+    }
+}
+
+impl From<crate::UQ8p8> for UQ16p8 {
+    fn from(v: crate::UQ8p8) -> UQ16p8 {
+        v.to_uq16p8()
+    }
+}
+
+impl PartialOrd for UQ16p8 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UQ16p8 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_q().cmp(&other.to_q())
+    }
+}
+
+impl core::fmt::Debug for UQ16p8 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("UQ16p8").field(&self.to_q()).finish()
+    }
+}
+
+impl core::ops::Add for UQ16p8 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        saturating!(self.overflowing_add(other), Self::add(self, other))
+    }
+}
+
+impl core::ops::AddAssign for UQ16p8 {
+    fn add_assign(&mut self, other: Self) {
+        self.0 = (*self + other).0;
+    }
+}
+
+impl core::ops::Sub for UQ16p8 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        saturating!(self.overflowing_sub(other), Self::sub(self, other))
+    }
+}
+
+impl core::ops::SubAssign for UQ16p8 {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 = (*self - other).0;
+    }
+}
+
+impl core::ops::Mul for UQ16p8 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(self, other)
+    }
+}
+
+impl core::ops::MulAssign for UQ16p8 {
+    fn mul_assign(&mut self, other: Self) {
+        self.0 = (*self * other).0;
+    }
+}
+
+impl core::ops::Div for UQ16p8 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::div(self, other)
+    }
+}
+
+impl core::ops::DivAssign for UQ16p8 {
+    fn div_assign(&mut self, other: Self) {
+        self.0 = (*self / other).0;
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
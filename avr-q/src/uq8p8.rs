@@ -0,0 +1,564 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{asm::asm_mulu16, round::Round, saturation::saturating};
+use avr_int24::I24;
+
+/// UQ8.8 unsigned fixed point number.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[repr(transparent)]
+pub struct UQ8p8(u16);
+
+/// Construct a UQ8.8 fixed point number.
+///
+/// The argument to this macro can be a single one.
+/// In this case it is the integer part of the UQ8.8 value and the fractional part is zero.
+///
+/// `uq8p8!(42)`
+///
+/// Or the arguments can be two numbers, separated by a slash.
+/// In this case the value is a fraction with a numerator and a denominator.
+///
+/// `uq8p8!(42 / 10)`
+///
+/// The arguments can be prefixed with `const` to enforce `const` evaluation.
+///
+/// `uq8p8!(const 42 / 10)`
+///
+/// The arguments can be either literals or identifiers.
+#[macro_export]
+macro_rules! uq8p8 {
+    (const $numerator:literal / $denominator:literal) => {
+        const { $crate::UQ8p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::UQ8p8::const_from_fraction($numerator, $denominator) }
+    };
+
+    (const $numerator:literal / $denominator:ident) => {
+        const { $crate::UQ8p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:literal / $denominator:ident) => {
+        $crate::UQ8p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:literal) => {
+        const { $crate::UQ8p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:literal) => {
+        $crate::UQ8p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:ident / $denominator:ident) => {
+        const { $crate::UQ8p8::const_from_fraction($numerator, $denominator) }
+    };
+    ($numerator:ident / $denominator:ident) => {
+        $crate::UQ8p8::from_fraction($numerator, $denominator)
+    };
+
+    (const $numerator:literal) => {
+        const { $crate::UQ8p8::from_int($numerator) }
+    };
+    ($numerator:literal) => {
+        const { $crate::UQ8p8::from_int($numerator) }
+    };
+
+    (const $numerator:ident) => {
+        const { $crate::UQ8p8::from_int($numerator) }
+    };
+    ($numerator:ident) => {
+        $crate::UQ8p8::from_int($numerator)
+    };
+}
+
+/// Convert a raw Q.8 value to [UQ8p8].
+/// The value saturates, if it doesn't fit.
+/// Returns the saturated value and `true`, if saturation happened.
+pub(crate) const fn uq8p8_from_q8(q: i32) -> (UQ8p8, bool) {
+    if q < 0 {
+        (UQ8p8(0), true)
+    } else if q > u16::MAX as i32 {
+        (UQ8p8(u16::MAX), true)
+    } else {
+        (UQ8p8(q as u16), false)
+    }
+}
+
+/// Convert and saturate a wide raw value to a raw UQ8.8 value.
+/// Returns the saturated value and `true`, if saturation happened.
+#[inline(always)]
+const fn overflowing_from_u32(v: u32) -> (u16, bool) {
+    if v > u16::MAX as u32 {
+        (u16::MAX, true)
+    } else {
+        (v as u16, false)
+    }
+}
+
+/// Shift the 32 bit product of two raw UQ8.8 values down to UQ8.8.
+/// The product is rounded according to `round`.
+/// Returns the saturated product and `true`, if saturation happened.
+#[inline(always)]
+const fn round_prod_uq8p8(p: u32, round: Round) -> (u16, bool) {
+    let q = p >> 8;
+    let q = q + round.round_up(false, q & 1 != 0, p & 0xFF, 0x100) as u32;
+    overflowing_from_u32(q)
+}
+
+/// Multiply two raw UQ8.8 values.
+/// The product is rounded according to `round`.
+/// Returns the saturated product and `true`, if saturation happened.
+#[inline(always)]
+fn mul_uq8p8(a: u16, b: u16, round: Round) -> (u16, bool) {
+    round_prod_uq8p8(asm_mulu16(a, b), round)
+}
+
+/// Const variant of [mul_uq8p8].
+#[inline(always)]
+const fn const_mul_uq8p8(a: u16, b: u16, round: Round) -> (u16, bool) {
+    round_prod_uq8p8(a as u32 * b as u32, round)
+}
+
+/// Divide two raw UQ8.8 values.
+/// The quotient is rounded according to `round`.
+/// Returns the saturated quotient and `true`, if saturation happened.
+#[inline(always)]
+const fn div_uq8p8(a: u16, b: u16, round: Round) -> (u16, bool) {
+    if b == 0 {
+        return (u16::MAX, true);
+    }
+    let n = (a as u32) << 8;
+    let q = n / b as u32;
+    let rem = n % b as u32;
+    let q = q + round.round_up(false, q & 1 != 0, rem, b as u32) as u32;
+    overflowing_from_u32(q)
+}
+
+#[allow(clippy::should_implement_trait)]
+impl UQ8p8 {
+    /// Length of the fractional part, in bits.
+    pub const SHIFT: usize = 8;
+
+    /// Convert a raw UQ8.8 value to [UQ8p8].
+    pub const fn from_q(q: u16) -> Self {
+        Self(q)
+    }
+
+    /// Convert an integer value to [UQ8p8] with fractional part being zero.
+    pub const fn from_int(int: u8) -> Self {
+        Self::from_q((int as u16) << Self::SHIFT)
+    }
+
+    /// Convert a numerator/denominator fraction to [UQ8p8].
+    pub fn from_fraction(numerator: u16, denominator: u16) -> Self {
+        Self(numerator) / Self(denominator)
+    }
+
+    /// Convert a numerator/denominator fraction to [UQ8p8].
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::from_fraction] instead.
+    pub const fn const_from_fraction(numerator: u16, denominator: u16) -> Self {
+        Self(numerator).const_div(Self(denominator))
+    }
+
+    /// Convert this [UQ8p8] to a raw UQ8.8 value.
+    pub const fn to_q(self) -> u16 {
+        self.0
+    }
+
+    /// Extract the integer part out of this [UQ8p8].
+    pub const fn to_int(self) -> u8 {
+        (self.to_q() >> Self::SHIFT) as u8
+    }
+
+    /// Convert this [UQ8p8] to a [crate::Q7p8].
+    /// The value saturates, if it doesn't fit.
+    pub const fn to_q7p8(&self) -> crate::Q7p8 {
+        crate::Q7p8::const_overflowing_from_i32(self.0 as i32).0
+    }
+
+    /// Convert this [UQ8p8] to a [crate::Q15p8].
+    pub const fn to_q15p8(&self) -> crate::Q15p8 {
+        crate::Q15p8::from_q(I24::from_i32(self.0 as i32))
+    }
+
+    /// Convert this [UQ8p8] to a [crate::UQ16p8].
+    pub const fn to_uq16p8(&self) -> crate::UQ16p8 {
+        crate::UQ16p8::from_q(self.0 as u32)
+    }
+
+    /// Add and saturate two [UQ8p8] values.
+    #[inline(never)]
+    pub const fn add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Subtract and saturate two [UQ8p8] values.
+    /// The difference saturates at zero.
+    #[inline(never)]
+    pub const fn sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    #[inline(never)]
+    pub fn mul(self, other: Self) -> Self {
+        Self(saturating!(
+            mul_uq8p8(self.0, other.0, Round::Floor),
+            mul_uq8p8(self.0, other.0, Round::Floor).0
+        ))
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::mul] instead.
+    pub const fn const_mul(self, other: Self) -> Self {
+        Self(const_mul_uq8p8(self.0, other.0, Round::Floor).0)
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div(self, other: Self) -> Self {
+        Self(saturating!(
+            div_uq8p8(self.0, other.0, Round::Floor),
+            div_uq8p8(self.0, other.0, Round::Floor).0
+        ))
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::div] instead.
+    pub const fn const_div(self, other: Self) -> Self {
+        Self(div_uq8p8(self.0, other.0, Round::Floor).0)
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    #[inline(never)]
+    pub fn mul_round(self, other: Self) -> Self {
+        Self(saturating!(
+            mul_uq8p8(self.0, other.0, Round::Nearest),
+            mul_uq8p8(self.0, other.0, Round::Nearest).0
+        ))
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// The product is rounded to nearest, with ties rounded away from zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::mul_round] instead.
+    pub const fn const_mul_round(self, other: Self) -> Self {
+        Self(const_mul_uq8p8(self.0, other.0, Round::Nearest).0)
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    #[inline(never)]
+    pub fn mul_round_even(self, other: Self) -> Self {
+        Self(saturating!(
+            mul_uq8p8(self.0, other.0, Round::NearestEven),
+            mul_uq8p8(self.0, other.0, Round::NearestEven).0
+        ))
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// The product is rounded to nearest, with ties rounded to even.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::mul_round_even] instead.
+    pub const fn const_mul_round_even(self, other: Self) -> Self {
+        Self(const_mul_uq8p8(self.0, other.0, Round::NearestEven).0)
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_round(self, other: Self) -> Self {
+        Self(saturating!(
+            div_uq8p8(self.0, other.0, Round::Nearest),
+            div_uq8p8(self.0, other.0, Round::Nearest).0
+        ))
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// The quotient is rounded to nearest, with ties rounded away from zero.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::div_round] instead.
+    pub const fn const_div_round(self, other: Self) -> Self {
+        Self(div_uq8p8(self.0, other.0, Round::Nearest).0)
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn div_round_even(self, other: Self) -> Self {
+        Self(saturating!(
+            div_uq8p8(self.0, other.0, Round::NearestEven),
+            div_uq8p8(self.0, other.0, Round::NearestEven).0
+        ))
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// The quotient is rounded to nearest, with ties rounded to even.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::div_round_even] instead.
+    pub const fn const_div_round_even(self, other: Self) -> Self {
+        Self(div_uq8p8(self.0, other.0, Round::NearestEven).0)
+    }
+
+    /// Add two [UQ8p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+
+    /// Subtract two [UQ8p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+
+    /// Multiply two [UQ8p8] values.
+    /// The result wraps around on overflow.
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        Self(((self.0 as u32 * other.0 as u32) >> Self::SHIFT) as u16)
+    }
+
+    /// Divide two [UQ8p8] values.
+    /// The quotient is truncated towards zero and wraps around on overflow.
+    /// Division by zero saturates.
+    pub const fn wrapping_div(self, other: Self) -> Self {
+        if other.0 == 0 {
+            self.const_div(other)
+        } else {
+            Self((((self.0 as u32) << Self::SHIFT) / other.0 as u32) as u16)
+        }
+    }
+
+    /// Negate this [UQ8p8] value.
+    /// The result wraps around, unless the value is zero.
+    pub const fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Add two [UQ8p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Subtract two [UQ8p8] values.
+    /// Returns `None` on overflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(c) => Some(Self(c)),
+            None => None,
+        }
+    }
+
+    /// Multiply two [UQ8p8] values.
+    /// Returns `None` on overflow.
+    #[inline(never)]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        match self.overflowing_mul(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Multiply two [UQ8p8] values.
+    /// Returns `None` on overflow.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::checked_mul] instead.
+    pub const fn const_checked_mul(self, other: Self) -> Option<Self> {
+        match self.const_overflowing_mul(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Divide two [UQ8p8] values.
+    /// Returns `None` on overflow or division by zero.
+    #[inline(never)]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.const_checked_div(other)
+    }
+
+    /// Divide two [UQ8p8] values.
+    /// Returns `None` on overflow or division by zero.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::checked_div] instead.
+    pub const fn const_checked_div(self, other: Self) -> Option<Self> {
+        match self.const_overflowing_div(other) {
+            (c, false) => Some(c),
+            (_, true) => None,
+        }
+    }
+
+    /// Add and saturate two [UQ8p8] values.
+    /// Returns the saturated sum and `true`, if saturation happened.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (_, sat) = self.0.overflowing_add(other.0);
+        (Self(self.0.saturating_add(other.0)), sat)
+    }
+
+    /// Subtract and saturate two [UQ8p8] values.
+    /// Returns the saturated difference and `true`, if saturation happened.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (_, sat) = self.0.overflowing_sub(other.0);
+        (Self(self.0.saturating_sub(other.0)), sat)
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    #[inline(never)]
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = mul_uq8p8(self.0, other.0, Round::Floor);
+        (Self(c), sat)
+    }
+
+    /// Multiply and saturate two [UQ8p8] values.
+    /// Returns the saturated product and `true`, if saturation happened.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::overflowing_mul] instead.
+    pub const fn const_overflowing_mul(self, other: Self) -> (Self, bool) {
+        let (c, sat) = const_mul_uq8p8(self.0, other.0, Round::Floor);
+        (Self(c), sat)
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    #[inline(never)]
+    pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+        self.const_overflowing_div(other)
+    }
+
+    /// Divide and saturate two [UQ8p8] values.
+    /// Returns the saturated quotient and `true`, if saturation happened.
+    /// Division by zero saturates, too.
+    /// Const variant.
+    ///
+    /// Only call this function from const context.
+    /// From non-const context use the optimized variant [UQ8p8::overflowing_div] instead.
+    pub const fn const_overflowing_div(self, other: Self) -> (Self, bool) {
+        let (c, sat) = div_uq8p8(self.0, other.0, Round::Floor);
+        (Self(c), sat)
+    }
+}
+
+impl From<u8> for UQ8p8 {
+    fn from(value: u8) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<UQ8p8> for u8 {
+    fn from(value: UQ8p8) -> Self {
+        value.to_int()
+    }
+}
+
+impl From<crate::Q7p8> for UQ8p8 {
+    fn from(v: crate::Q7p8) -> UQ8p8 {
+        saturating!(uq8p8_from_q8(v.to_q() as i32), v.to_uq8p8())
+    }
+}
+
+impl From<crate::Q15p8> for UQ8p8 {
+    fn from(v: crate::Q15p8) -> UQ8p8 {
+        saturating!(uq8p8_from_q8(v.to_q().to_i32()), v.to_uq8p8())
+    }
+}
+
+impl From<crate::UQ16p8> for UQ8p8 {
+    fn from(v: crate::UQ16p8) -> UQ8p8 {
+        saturating!(uq8p8_from_q8(v.to_q() as i32), v.to_uq8p8())
+    }
+}
+
+impl core::ops::Add for UQ8p8 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        saturating!(self.overflowing_add(other), Self::add(self, other))
+    }
+}
+
+impl core::ops::AddAssign for UQ8p8 {
+    fn add_assign(&mut self, other: Self) {
+        self.0 = (*self + other).0;
+    }
+}
+
+impl core::ops::Sub for UQ8p8 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        saturating!(self.overflowing_sub(other), Self::sub(self, other))
+    }
+}
+
+impl core::ops::SubAssign for UQ8p8 {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 = (*self - other).0;
+    }
+}
+
+impl core::ops::Mul for UQ8p8 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(self, other)
+    }
+}
+
+impl core::ops::MulAssign for UQ8p8 {
+    fn mul_assign(&mut self, other: Self) {
+        self.0 = (*self * other).0;
+    }
+}
+
+impl core::ops::Div for UQ8p8 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::div(self, other)
+    }
+}
+
+impl core::ops::DivAssign for UQ8p8 {
+    fn div_assign(&mut self, other: Self) {
+        self.0 = (*self / other).0;
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,101 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+/// Fixed point number with wrapping arithmetic.
+///
+/// The operators of this type wrap around on overflow
+/// instead of saturating the result.
+///
+/// `Wrapping(q7p8!(const 127)) + Wrapping(q7p8!(const 1)) == Wrapping(q7p8!(const -128))`
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+macro_rules! impl_wrapping {
+    ([$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> From<$type> for Wrapping<$type> {
+            fn from(v: $type) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<$($generics)*> core::ops::Add for Wrapping<$type> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self(self.0.wrapping_add(other.0))
+            }
+        }
+
+        impl<$($generics)*> core::ops::AddAssign for Wrapping<$type> {
+            fn add_assign(&mut self, other: Self) {
+                self.0 = (*self + other).0;
+            }
+        }
+
+        impl<$($generics)*> core::ops::Sub for Wrapping<$type> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self(self.0.wrapping_sub(other.0))
+            }
+        }
+
+        impl<$($generics)*> core::ops::SubAssign for Wrapping<$type> {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 = (*self - other).0;
+            }
+        }
+
+        impl<$($generics)*> core::ops::Mul for Wrapping<$type> {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self(self.0.wrapping_mul(other.0))
+            }
+        }
+
+        impl<$($generics)*> core::ops::MulAssign for Wrapping<$type> {
+            fn mul_assign(&mut self, other: Self) {
+                self.0 = (*self * other).0;
+            }
+        }
+
+        impl<$($generics)*> core::ops::Div for Wrapping<$type> {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                Self(self.0.wrapping_div(other.0))
+            }
+        }
+
+        impl<$($generics)*> core::ops::DivAssign for Wrapping<$type> {
+            fn div_assign(&mut self, other: Self) {
+                self.0 = (*self / other).0;
+            }
+        }
+
+        impl<$($generics)*> core::ops::Neg for Wrapping<$type> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+    };
+    ($type:ty) => {
+        impl_wrapping!([] $type);
+    };
+}
+
+impl_wrapping!(crate::Q7p8);
+impl_wrapping!(crate::Q15p8);
+impl_wrapping!(crate::UQ8p8);
+impl_wrapping!(crate::UQ16p8);
+impl_wrapping!([const FRAC: usize] crate::Q<i8, FRAC>);
+impl_wrapping!([const FRAC: usize] crate::Q<i16, FRAC>);
+impl_wrapping!([const FRAC: usize] crate::Q<avr_int24::I24, FRAC>);
+impl_wrapping!([const FRAC: usize] crate::Q<i32, FRAC>);
+
+// vim: ts=4 sw=4 expandtab